@@ -1,45 +1,140 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
 use crossbeam::channel::{Receiver, Sender, unbounded};
-use crossterm::event::{self};
-use ratatui::{DefaultTerminal, layout::Direction};
+use crossterm::{
+    cursor,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEvent},
+    execute,
+    terminal::{self, EnterAlternateScreen},
+};
+use ratatui::{
+    DefaultTerminal,
+    layout::{Direction, Rect},
+};
 
 use crate::{
-    config::Config,
-    directory_entry::DirectoryEntryType,
-    types::CircularBuffer,
-    utils::file::{FileError, get_opening_methods, open_file},
+    config::{self, Config},
+    directory_entry::{DirectoryEntryType, vfs},
+    keymap::{Key, KeySequence, Keymap, Mapping},
+    state::{History, HistoryKind, LAST_SESSION, OpeningChoices},
+    utils::file::{open_file_in_terminal, watch_file},
 };
 
 use super::{
-    AppEvent, Error, InputMode, Result,
-    input::handle_event,
+    AppEvent, Error, InputMode, Picker, Result, WindowLayout,
+    diff::ActiveDiff,
+    input::{execute_command_line, handle_event},
     ui::render_ui,
     window::Window,
-    windows::{DummyWindow, FileManagerWindow},
+    windows::{DummyWindow, FileManagerWindow, SplitWindow},
 };
 
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default bindings with the config ones applied, reporting the last one that failed
+fn build_keymap(config: &Config) -> (Keymap, Option<Error>) {
+    let mut keymap = Keymap::new();
+    let mut error = None;
+
+    for (sequence, mapping) in config.keys().normal() {
+        if let Err(e) = keymap.bind(sequence, mapping.clone()) {
+            error = Some(Error::Keymap(e));
+        }
+    }
+
+    (keymap, error)
+}
+
+/// How deep mappings may expand into other mappings before it is treated as a loop
+const MAX_MAPPING_DEPTH: usize = 100;
+
 pub struct App {
     running: bool,
     pub input_mode: InputMode,
     pub error: Option<Error>,
+    /// Shown in the status line when there is no error, e.g. the answer to `:set option?`
+    pub message: Option<String>,
     pub window: Box<dyn Window>,
+    /// Where the windows were drawn last, for the mouse
+    pub(super) window_area: Rect,
+    /// Where each window was drawn last, for moving the focus by position
+    pub(super) window_areas: Vec<(u32, Rect)>,
+    /// The split border being dragged with the mouse, see `Window::border_at`
+    pub(super) dragged_border: Option<(u32, usize)>,
+    /// When and where the last click was, to tell double clicks
+    pub(super) last_click: Option<(Instant, u16, u16)>,
     pub config: Config,
-    pub command_history: CircularBuffer<String>,
-    event_rx: Receiver<AppEvent>,
+    /// Watched for changes and loaded by `:source` without a file, even when loading it failed
+    config_path: PathBuf,
+    pub keymap: Keymap,
+    /// Set while replaying the keys of a `noremap` mapping so only default bindings apply
+    pub noremap: bool,
+    mapping_depth: usize,
+    pub history: History,
+    pub opening_choices: OpeningChoices,
+    /// Where the directory quit in is written for `--choosedir`
+    choosedir: Option<PathBuf>,
+    /// The directory focused when quitting, kept when the last window closes with it
+    chosen_directory: Option<PathBuf>,
+    /// The layout of the last window, kept when closing it leaves no windows to save
+    pub(super) last_layout: Option<WindowLayout>,
+    pub(super) picker: Option<Picker>,
+    pub(super) picked: Vec<PathBuf>,
+    /// Socket of the remote control server, passed on to plugins
+    pub(super) socket_path: Option<PathBuf>,
+    /// The windows compared by `:diff`, kept to refresh and sync them
+    pub(super) diff: Option<ActiveDiff>,
+    /// Jobs spawned in the background that did not finish yet
+    pub(super) running_jobs: usize,
+    event_tx: Sender<AppEvent>,
+    pub(super) event_rx: Receiver<AppEvent>,
+    clear_terminal: bool,
 }
 
 impl App {
-    pub fn new(directory: &str, config: Config) -> Result<(Self, Sender<AppEvent>)> {
+    pub fn new(
+        directory: &str,
+        config: Config,
+        config_path: PathBuf,
+    ) -> Result<(Self, Sender<AppEvent>)> {
         let (tx, rx) = unbounded();
 
+        let (keymap, error) = build_keymap(&config);
+
         Ok((
             Self {
                 running: false,
                 input_mode: InputMode::Normal { precommand: None },
-                error: None,
+                error,
+                message: None,
                 window: Box::new(FileManagerWindow::new(directory)?),
-                command_history: CircularBuffer::new(config.history().size()),
+                window_area: Rect::default(),
+                window_areas: Vec::new(),
+                dragged_border: None,
+                last_click: None,
+                keymap,
+                noremap: false,
+                mapping_depth: 0,
+                history: History::load(config.history().size()),
                 config,
+                config_path,
+                opening_choices: OpeningChoices::load(),
+                choosedir: None,
+                chosen_directory: None,
+                last_layout: None,
+                picker: None,
+                picked: Vec::new(),
+                socket_path: None,
+                diff: None,
+                running_jobs: 0,
+                event_tx: tx.clone(),
                 event_rx: rx,
+                clear_terminal: false,
             },
             tx,
         ))
@@ -49,6 +144,7 @@ impl App {
         self.running = true;
         self.input_mode = InputMode::Normal { precommand: None };
         self.error = None;
+        self.message = None;
         self.window.reset(&self.config)?;
 
         Ok(())
@@ -56,7 +152,20 @@ impl App {
 
     pub fn run(mut self, mut terminal: DefaultTerminal, event_tx: &Sender<AppEvent>) -> Result<()> {
         self.running = true;
+
+        if !self.config_path.as_os_str().is_empty() {
+            let tx = event_tx.clone();
+            watch_file(self.config_path.clone(), CONFIG_WATCH_INTERVAL, move || {
+                tx.send(AppEvent::ReloadConfig).is_ok()
+            });
+        }
+
         while self.running {
+            if self.clear_terminal {
+                self.clear_terminal = false;
+                terminal.clear()?;
+            }
+
             terminal.draw(|frame| render_ui(&mut self, frame))?;
 
             self.handle_crossterm_events(event_tx)?;
@@ -65,71 +174,301 @@ impl App {
                 self.handle_app_events(event)?;
             }
         }
+
+        self.wait_for_jobs(&mut terminal)?;
+
+        // there is nowhere left to report a failure, the previous last session is kept then
+        if let Some(session) = self.last_session() {
+            let _ = session.save(LAST_SESSION);
+        }
+        vfs::remove_temp_directory();
+
+        if let Some(file) = &self.choosedir {
+            let directory = self
+                .chosen_directory
+                .take()
+                .or_else(|| self.focused_directory())
+                .map(|directory| directory.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            fs::write(file, directory)?;
+        }
+
+        if let Some(picker) = &mut self.picker {
+            picker.write(&self.picked)?;
+        }
+
         Ok(())
     }
 
     fn handle_crossterm_events(&mut self, event_tx: &Sender<AppEvent>) -> Result<()> {
+        // waking up regularly lets events sent from other threads show up without a key press
+        if !event::poll(EVENT_POLL_INTERVAL)? {
+            return Ok(());
+        }
+
         let event = event::read()?;
 
-        let handled = handle_event(self, &event);
+        self.handle_terminal_event(&event, event_tx);
+
+        Ok(())
+    }
+
+    fn handle_terminal_event(&mut self, event: &Event, event_tx: &Sender<AppEvent>) {
+        let handled = handle_event(self, event);
         self.window
-            .handle_event(&self.input_mode, &event, true, event_tx, handled);
+            .handle_event(&self.input_mode, event, true, event_tx, handled);
+    }
+
+    /// Replays `keys` as if they were typed, user mappings only apply to them when `remap` is set
+    pub fn feed_keys(&mut self, keys: &[Key], remap: bool) -> Result<()> {
+        if self.mapping_depth >= MAX_MAPPING_DEPTH {
+            return Err(Error::RecursiveMapping);
+        }
+
+        self.mapping_depth += 1;
+        let noremap = self.noremap;
+        self.noremap = noremap || !remap;
+        let event_tx = self.event_tx.clone();
+
+        let result = keys.iter().try_for_each(|key| {
+            let event = Event::Key(KeyEvent::new(key.code(), key.modifiers()));
+            self.handle_terminal_event(&event, &event_tx);
+
+            while let Ok(event) = self.event_rx.try_recv() {
+                self.handle_app_events(event)?;
+            }
+
+            Ok(())
+        });
+
+        self.noremap = noremap;
+        self.mapping_depth -= 1;
+
+        result
+    }
+
+    /// Runs a command line as if it was entered after `:`
+    pub fn execute_command_line(&mut self, line: &str) {
+        execute_command_line(self, line);
+    }
+
+    /// Loads the config from `path`, or the config file the app started with, and applies it
+    pub fn source(&mut self, path: Option<PathBuf>) {
+        let path = path.unwrap_or_else(|| self.config_path.clone());
+
+        match Config::try_from(path.clone()) {
+            Ok(config) => {
+                self.apply_config(config);
+
+                if self.error.is_none() {
+                    self.message = Some(format!("\"{}\" sourced", path.display()));
+                }
+            }
+            Err(e) => self.error = Some(e.into()),
+        }
+    }
+
+    /// Replaces the config and updates everything derived from it
+    pub fn apply_config(&mut self, config: Config) {
+        let (keymap, error) = build_keymap(&config);
+
+        self.keymap = keymap;
+        self.error = error;
+        self.history.set_size(config.history().size());
+        self.config = config;
+
+        if let Err(e) = self.window.reset(&self.config) {
+            self.error = Some(e);
+        }
+    }
+
+    /// Binds `lhs` in the keymap and records it in the config so `:w` persists it
+    pub fn map(&mut self, lhs: &str, mapping: Mapping) -> Result<()> {
+        self.keymap.bind(lhs, mapping.clone())?;
+        self.config
+            .mut_keys()
+            .mut_normal()
+            .insert(lhs.to_string(), mapping);
+
+        Ok(())
+    }
+
+    /// Removes the mapping of `lhs` from the keymap and the config, its default binding, if any,
+    /// applies again
+    pub fn unmap(&mut self, lhs: &str) -> Result<()> {
+        let sequence: KeySequence = lhs.parse()?;
+
+        self.keymap.unbind(&sequence);
+        self.config
+            .mut_keys()
+            .mut_normal()
+            .retain(|existing, _| existing.parse::<KeySequence>().ok().as_ref() != Some(&sequence));
+
+        Ok(())
+    }
+
+    /// Adds a line to the command history and writes the history to the data directory
+    pub fn remember_command(&mut self, line: &str) -> Result<()> {
+        self.history.push(HistoryKind::Command, line.to_string());
+        self.history.save()?;
 
         Ok(())
     }
 
+    /// Changes the directory of the focused window, opening it elsewhere when the focused window
+    /// cannot show directories
+    pub fn change_directory(&mut self, directory: PathBuf) {
+        match self.window.change_directory(&directory) {
+            Ok(true) => {}
+            Ok(false) => {
+                let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
+
+                (self.window, self.error) = window.open(directory, false);
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
     fn handle_app_events(&mut self, event: AppEvent) -> Result<()> {
         match event {
-            AppEvent::UpdatePrecommand(new_precommand) => {
-                if let InputMode::Normal { precommand } = &mut self.input_mode {
-                    *precommand = new_precommand
-                }
-            }
             AppEvent::SetError(e) => self.error = e,
             AppEvent::UpdateInputMode(input_mode) => self.input_mode = input_mode,
             AppEvent::Open {
                 path,
                 new,
                 entry_type,
-            } => match entry_type {
-                DirectoryEntryType::Directory => {
-                    let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
-
-                    (self.window, self.error) = window.open(path, new);
+            } => {
+                // while picking, opening picks instead
+                if !new && self.pick(&path) {
+                    return Ok(());
                 }
-                _ => {
-                    if new {
-                        match get_opening_methods(&path) {
-                            Ok(apps) => {
-                                if apps.is_empty() {
-                                    self.error = Some(FileError::NoAppsFound.into());
-                                } else {
-                                    self.input_mode = InputMode::Opening {
-                                        apps,
-                                        path: path.to_string_lossy().to_string(),
-                                        selected_index: 0,
-                                    };
-                                }
-                            }
-                            Err(e) => {
-                                self.error = Some(e.into());
-                            }
+
+                match entry_type {
+                    DirectoryEntryType::Directory => {
+                        let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
+
+                        (self.window, self.error) = window.open(path, new);
+                    }
+                    _ => {
+                        if new {
+                            self.show_opening_methods(&path);
+                        } else {
+                            self.open_file(&path);
                         }
-                    } else {
-                        let _ = open_file(&path);
                     }
                 }
-            },
+            }
+            AppEvent::Edit(path) => self.edit(&path),
             AppEvent::Reset => self.reset()?,
+            AppEvent::ChangeDirectory(directory) => self.change_directory(directory),
+            AppEvent::Select(path) => {
+                if !self.window.select(&path) {
+                    self.error = Some(Error::InvalidDirectoryPath(
+                        path.to_string_lossy().to_string(),
+                    ));
+                }
+            }
+            AppEvent::Message(message) => {
+                self.error = None;
+                self.message = Some(message);
+            }
+            AppEvent::JobFinished(result) => self.finish_job(result),
+            AppEvent::ReloadConfig => {
+                if self.config_path.exists() {
+                    self.source(None);
+                }
+            }
+            AppEvent::Remote { request, reply } => {
+                let _ = reply.send(self.answer(request));
+            }
         }
 
         Ok(())
     }
 
+    pub fn event_tx(&self) -> &Sender<AppEvent> {
+        &self.event_tx
+    }
+
+    /// Leaves the TUI, runs `f` with the terminal handed back to the user and restores the TUI
+    pub fn suspend<T>(&mut self, f: impl FnOnce() -> T) -> Result<T> {
+        execute!(io::stdout(), DisableMouseCapture)?;
+        ratatui::restore();
+        execute!(io::stdout(), cursor::Show)?;
+
+        let result = f();
+
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            cursor::Hide
+        )?;
+        self.clear_terminal = true;
+
+        Ok(result)
+    }
+
+    pub fn edit(&mut self, path: &Path) {
+        let editor = self.config.opener().editor().map(str::to_string);
+
+        match self
+            .suspend(|| open_file_in_terminal(path, editor.as_deref()))
+            .and_then(|result| Ok(result?))
+        {
+            Ok(_) => {
+                let _ = self.event_tx.send(AppEvent::Reset);
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Opens the config file in the editor and loads it once the editor exits
+    pub fn edit_config(&mut self) {
+        if self.config_path.as_os_str().is_empty() {
+            self.error = Some(config::Error::NoPath.into());
+            return;
+        }
+
+        let editor = self.config.opener().editor().map(str::to_string);
+        let path = self.config_path.clone();
+
+        match self
+            .suspend(|| open_file_in_terminal(&path, editor.as_deref()))
+            .and_then(|result| Ok(result?))
+        {
+            Ok(_) => self.source(None),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
 
+    /// Quits every window, writing the focused directory to the `--choosedir` file as any quit
+    /// does
+    pub fn quit_choosing_directory(&mut self) {
+        self.chosen_directory = self.focused_directory();
+        self.quit();
+    }
+
+    fn focused_directory(&self) -> Option<PathBuf> {
+        self.window
+            .focused_context()
+            .and_then(|context| context.directory)
+    }
+
+    pub fn set_choosedir(&mut self, file: PathBuf) {
+        self.choosedir = Some(file);
+    }
+
+    pub fn set_socket_path(&mut self, path: PathBuf) {
+        self.socket_path = Some(path);
+    }
+
     pub fn next_window(&mut self) {
         if !self.window.next_window() {
             self.window.abs_next_window();
@@ -143,11 +482,15 @@ impl App {
     }
 
     pub fn quit_focused_window(&mut self) {
+        let directory = self.focused_directory();
+        let layout = self.window.layout();
         let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
 
         if let Some(window) = window.quit_focused_window() {
             self.window = window;
         } else {
+            self.chosen_directory = directory;
+            self.last_layout = layout;
             self.quit();
         }
     }
@@ -155,4 +498,60 @@ impl App {
     pub fn adjust_window_size(&mut self, direction: Direction, adjustment: isize) {
         self.window.adjust_window_size(direction, adjustment, None);
     }
+
+    /// Closes every window but the focused one
+    pub fn only_window(&mut self) {
+        let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
+        let (mut focused, _) = window.take_focused_window();
+
+        focused.set_window_size(focused.default_window_size());
+        self.window = focused;
+    }
+
+    pub fn equalize_windows(&mut self) {
+        self.window.equalize_windows();
+    }
+
+    pub fn maximize_window(&mut self, direction: Direction) {
+        self.window.maximize_window(direction);
+    }
+
+    pub fn swap_window(&mut self) {
+        self.window.swap_window();
+    }
+
+    pub fn rotate_windows(&mut self) {
+        self.window.rotate_windows();
+    }
+
+    /// Moves the focused window to the far start or end of the screen in `direction`, taking the
+    /// full height or width
+    pub fn move_window_to_edge(&mut self, direction: Direction, first: bool) {
+        let window = std::mem::replace(&mut self.window, Box::new(DummyWindow));
+        let (mut focused, rest) = window.take_focused_window();
+
+        let Some(mut rest) = rest else {
+            self.window = focused;
+            return;
+        };
+
+        focused.set_window_size(focused.default_window_size());
+
+        self.window = match rest.insert_at_edge(focused, direction, first) {
+            None => rest,
+            Some(focused) => {
+                let (windows, focused_index) = if first {
+                    (vec![focused, rest], 0)
+                } else {
+                    (vec![rest, focused], 1)
+                };
+
+                Box::new(SplitWindow::with_focused_index(
+                    direction,
+                    windows,
+                    focused_index,
+                ))
+            }
+        };
+    }
 }
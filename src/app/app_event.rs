@@ -1,11 +1,13 @@
 use std::path::PathBuf;
 
-use crate::directory_entry::DirectoryEntryType;
+use crossbeam::channel::Sender;
+use serde_json::Value;
 
-use super::{Error, InputMode, precommand::Precommand};
+use crate::{directory_entry::DirectoryEntryType, remote::Request};
+
+use super::{Error, InputMode, Result};
 
 pub enum AppEvent {
-    UpdatePrecommand(Option<Precommand>),
     SetError(Option<Error>),
     UpdateInputMode(InputMode),
     Open {
@@ -13,5 +15,22 @@ pub enum AppEvent {
         new: bool,
         entry_type: DirectoryEntryType,
     },
+    /// Opens the file in the terminal editor with the TUI suspended
+    Edit(PathBuf),
     Reset,
+    /// Changes the directory of the focused window
+    ChangeDirectory(PathBuf),
+    /// Selects the entry at the path in the focused window
+    Select(PathBuf),
+    /// Shows the text in the status line
+    Message(String),
+    /// A job started with `App::spawn_job` ended
+    JobFinished(Result<String>),
+    /// The config file changed on disk
+    ReloadConfig,
+    /// A request from the socket, answered on `reply`
+    Remote {
+        request: Request,
+        reply: Sender<Value>,
+    },
 }
@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+
+use crate::directory_entry::{
+    self,
+    vfs::{self, ArchiveKind, Location},
+};
+
+use super::App;
+
+impl App {
+    /// Packs the marked entries of the focused window, or its selected entry, into the archive
+    /// `name` in the background, its kind told by the extension
+    pub fn compress(&mut self, name: &Path) {
+        let context = self.window.focused_context().unwrap_or_default();
+        let Some(directory) = context.directory else {
+            return;
+        };
+        let archive = directory.join(name);
+
+        if vfs::is_virtual_directory(&directory) {
+            self.error = Some(directory_entry::Error::ReadOnly(directory).into());
+            return;
+        }
+        if ArchiveKind::from_path(&archive).is_none() {
+            self.error = Some(directory_entry::Error::UnsupportedArchive(archive).into());
+            return;
+        }
+
+        let sources: Vec<PathBuf> = if context.marked.is_empty() {
+            context.selected.into_iter().collect()
+        } else {
+            context.marked
+        };
+        if sources.is_empty() {
+            return;
+        }
+
+        let shown = archive.display().to_string();
+        self.spawn_job(format!("Compressing into {shown}"), move || {
+            vfs::create_archive(&archive, &sources)?;
+
+            Ok(format!(
+                "{} {} compressed into {shown}",
+                sources.len(),
+                if sources.len() == 1 {
+                    "entry"
+                } else {
+                    "entries"
+                }
+            ))
+        });
+    }
+
+    /// Extracts the selected archive, or the selected entry of a browsed archive, in the
+    /// background into `destination`, relative to the focused directory, next to the archive by
+    /// default
+    pub fn extract(&mut self, destination: Option<PathBuf>) {
+        let context = self.window.focused_context().unwrap_or_default();
+        let (Some(directory), Some(selected)) = (context.directory, context.selected) else {
+            return;
+        };
+        let Location::Archive { archive, inner, .. } = Location::resolve(&selected) else {
+            self.error = Some(directory_entry::Error::UnsupportedArchive(selected).into());
+            return;
+        };
+        let destination = match destination {
+            Some(destination) => directory.join(destination),
+            None => archive.parent().unwrap_or(&archive).to_owned(),
+        };
+
+        if vfs::is_virtual_directory(&destination) {
+            self.error = Some(directory_entry::Error::ReadOnly(destination).into());
+            return;
+        }
+
+        let whole = inner.as_os_str().is_empty();
+        let name = selected
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        self.spawn_job(format!("Extracting {name}"), move || {
+            let target = if whole {
+                vfs::extract_archive(&selected, &destination)
+            } else {
+                vfs::extract(&selected, &destination)
+            }?;
+
+            Ok(format!("Extracted {name} to {}", target.display()))
+        });
+    }
+}
@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use crate::{
+    directory_entry::{self, vfs},
+    utils::file::{copy_path, move_path},
+};
+
+use super::{
+    App, Error,
+    windows::{FileManagerWindow, SplitWindow},
+};
+
+impl App {
+    /// Replaces the windows with two panes side by side, the focused directory on the left and
+    /// `directory`, or the same one, on the right
+    pub fn start_commander(&mut self, directory: Option<PathBuf>) {
+        let left = match self
+            .window
+            .focused_context()
+            .and_then(|context| context.directory)
+        {
+            Some(directory) => directory,
+            None => match std::env::current_dir() {
+                Ok(directory) => directory,
+                Err(e) => {
+                    self.error = Some(e.into());
+                    return;
+                }
+            },
+        };
+        let right = directory.unwrap_or_else(|| left.clone());
+
+        let panes = FileManagerWindow::new(&left.to_string_lossy())
+            .and_then(|left| Ok((left, FileManagerWindow::new(&right.to_string_lossy())?)));
+
+        match panes {
+            Ok((left, right)) => {
+                self.window = Box::new(SplitWindow::commander(Box::new(left), Box::new(right)));
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    pub fn switch_pane(&mut self) {
+        if !self.window.switch_pane() {
+            self.next_window();
+        }
+    }
+
+    /// Copies, or moves, the marked entries of the focused pane, or its selected entry, into the
+    /// directory of the other pane in the background, entries of archives are extracted and can't
+    /// be moved
+    pub fn transfer_to_other_pane(&mut self, moving: bool) {
+        let Some(target) = self
+            .window
+            .other_pane()
+            .and_then(|context| context.directory)
+        else {
+            self.error = Some(Error::NoOtherPane);
+            return;
+        };
+        if vfs::is_virtual_directory(&target) {
+            self.error = Some(directory_entry::Error::ReadOnly(target).into());
+            return;
+        }
+        let context = self.window.focused_context().unwrap_or_default();
+        let sources: Vec<PathBuf> = if context.marked.is_empty() {
+            context.selected.into_iter().collect()
+        } else {
+            context.marked
+        };
+        if sources.is_empty() {
+            return;
+        }
+
+        let description = format!(
+            "{} {} to {}",
+            if moving { "Moving" } else { "Copying" },
+            match sources.len() {
+                1 => "1 entry".to_string(),
+                count => format!("{count} entries"),
+            },
+            target.display()
+        );
+
+        self.spawn_job(description, move || {
+            let mut done = 0;
+            let mut error: Option<Error> = None;
+
+            for source in &sources {
+                let result = if vfs::is_virtual(source) {
+                    if moving {
+                        Err(directory_entry::Error::ReadOnly(source.to_owned()).into())
+                    } else {
+                        vfs::extract(source, &target).map_err(Error::from)
+                    }
+                } else if moving {
+                    move_path(source, &target).map_err(Error::from)
+                } else {
+                    copy_path(source, &target).map_err(Error::from)
+                };
+
+                match result {
+                    Ok(_) => done += 1,
+                    Err(e) => error = Some(e),
+                }
+            }
+
+            match error {
+                Some(e) => Err(e),
+                None => Ok(format!(
+                    "{done} {} {} to {}",
+                    if done == 1 { "entry" } else { "entries" },
+                    if moving { "moved" } else { "copied" },
+                    target.display()
+                )),
+            }
+        });
+    }
+}
@@ -0,0 +1,201 @@
+use std::fs::{self, File};
+
+use crate::{
+    directory_entry::{DirectoryDiff, Side, SyncCopy},
+    utils::file::{FileError, copy_path},
+};
+
+use super::{App, Error, InputMode, Result};
+
+/// The two windows compared by `:diff`
+pub struct ActiveDiff {
+    left_id: u32,
+    right_id: u32,
+    content: bool,
+    diff: DirectoryDiff,
+}
+
+/// Copies the source of `copy` over, keeping the modification time of files so they compare
+/// the same afterwards
+fn sync_copy(copy: &SyncCopy) -> std::result::Result<(), FileError> {
+    if copy.replace {
+        fs::copy(&copy.source, &copy.destination)?;
+    } else if let Some(parent) = copy.destination.parent() {
+        copy_path(&copy.source, parent)?;
+    }
+
+    let metadata = fs::metadata(&copy.source)?;
+    if metadata.is_file() {
+        File::options()
+            .write(true)
+            .open(&copy.destination)?
+            .set_modified(metadata.modified()?)?;
+    }
+
+    Ok(())
+}
+
+impl App {
+    /// Compares the directory of the focused window with the one of the other pane, or of the
+    /// only other window showing a directory
+    pub fn diff(&mut self, content: bool) {
+        if let Err(e) = self.start_diff(content) {
+            self.error = Some(e);
+        }
+    }
+
+    fn start_diff(&mut self, content: bool) -> Result<()> {
+        let left = self.window.focused_context().unwrap_or_default();
+        let right = match self.window.other_pane() {
+            Some(context) => context,
+            None => {
+                let mut others: Vec<_> = self
+                    .window
+                    .window_contexts()
+                    .into_iter()
+                    .filter(|context| context.id != left.id && context.directory.is_some())
+                    .collect();
+
+                match others.len() {
+                    1 => others.remove(0),
+                    _ => return Err(Error::NoOtherPane),
+                }
+            }
+        };
+        let (Some(left_directory), Some(right_directory)) = (left.directory, right.directory)
+        else {
+            return Err(Error::NoOtherPane);
+        };
+
+        self.diff_off();
+        self.diff = Some(ActiveDiff {
+            left_id: left.id,
+            right_id: right.id,
+            content,
+            diff: DirectoryDiff::compare(&left_directory, &right_directory, content)?,
+        });
+        self.show_diff();
+
+        let (only_left, only_right, different) = self
+            .diff
+            .as_ref()
+            .map_or((0, 0, 0), |active| active.diff.counts());
+        self.message = Some(format!(
+            "{only_left} only here, {only_right} only there, {different} different"
+        ));
+
+        Ok(())
+    }
+
+    fn show_diff(&mut self) {
+        if let Some(active) = &self.diff {
+            self.window
+                .set_diff(active.left_id, Some(active.diff.statuses(Side::Left)));
+            self.window
+                .set_diff(active.right_id, Some(active.diff.statuses(Side::Right)));
+        }
+    }
+
+    /// Compares the same directories again, failing when either window shows another one now
+    fn refresh_diff(&mut self) -> Result<()> {
+        let Some(active) = &self.diff else {
+            return Err(Error::NoDiff);
+        };
+        let directory = |id| {
+            self.window
+                .window_contexts()
+                .into_iter()
+                .find(|context| context.id == id)
+                .and_then(|context| context.directory)
+        };
+        let (left, right) = (
+            active.diff.directory(Side::Left),
+            active.diff.directory(Side::Right),
+        );
+
+        if directory(active.left_id).as_deref() != Some(left)
+            || directory(active.right_id).as_deref() != Some(right)
+        {
+            self.diff_off();
+            return Err(Error::NoDiff);
+        }
+
+        let diff = DirectoryDiff::compare(left, right, active.content)?;
+        if let Some(active) = &mut self.diff {
+            active.diff = diff;
+        }
+        self.show_diff();
+
+        Ok(())
+    }
+
+    pub fn diff_off(&mut self) {
+        if let Some(active) = self.diff.take() {
+            self.window.set_diff(active.left_id, None);
+            self.window.set_diff(active.right_id, None);
+        }
+    }
+
+    /// Previews the copies that sync the compared directories, `put` into the other window
+    /// rather than into the focused one
+    pub fn plan_sync(&mut self, put: bool) {
+        if let Err(e) = self.refresh_diff() {
+            self.error = Some(e);
+            return;
+        }
+        let Some(active) = &self.diff else {
+            return;
+        };
+
+        let focused = self.window.focused_window_id();
+        let side = if focused == active.left_id {
+            Side::Left
+        } else if focused == active.right_id {
+            Side::Right
+        } else {
+            self.error = Some(Error::NoDiff);
+            return;
+        };
+
+        let plan = active.diff.sync_plan(if put { side.other() } else { side });
+        if plan.is_empty() {
+            self.message = Some("Nothing to sync".to_string());
+        } else {
+            self.input_mode = InputMode::Syncing {
+                plan,
+                confirm_selected: false,
+            };
+        }
+    }
+
+    pub fn sync(&mut self, plan: &[SyncCopy]) {
+        let mut done = 0;
+        let mut error: Option<FileError> = None;
+
+        for copy in plan {
+            match sync_copy(copy) {
+                Ok(_) => done += 1,
+                Err(e) => error = Some(e),
+            }
+        }
+
+        if let Err(e) = self
+            .window
+            .reset(&self.config)
+            .and_then(|_| self.refresh_diff())
+        {
+            self.error = Some(e);
+            return;
+        }
+
+        match error {
+            Some(e) => self.error = Some(e.into()),
+            None => {
+                self.message = Some(format!(
+                    "{done} {} synced",
+                    if done == 1 { "entry" } else { "entries" }
+                ))
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::{fmt::Display, io};
 
-use crate::{command, config, directory_entry, utils::file::FileError};
+use crate::{
+    command, config, directory_entry, keymap, plugin, remote, state, utils::file::FileError,
+};
 
 #[derive(Debug)]
 pub enum Error {
@@ -12,6 +14,14 @@ pub enum Error {
     File(FileError),
     Command(command::Error),
     Config(config::Error),
+    State(state::Error),
+    Keymap(keymap::Error),
+    Remote(remote::Error),
+    Plugin(plugin::Error),
+    RecursiveMapping,
+    EmptyLayout,
+    NoOtherPane,
+    NoDiff,
     // Called something on a dummy that shouldn't
     NotADummy,
 }
@@ -29,6 +39,14 @@ impl Display for Error {
             Self::File(e) => write!(f, "File error: {e}"),
             Self::Command(e) => write!(f, "Command parse error: {e}"),
             Self::Config(e) => write!(f, "Config error: {e}"),
+            Self::State(e) => write!(f, "State error: {e}"),
+            Self::Keymap(e) => write!(f, "Keymap error: {e}"),
+            Self::Remote(e) => write!(f, "Remote error: {e}"),
+            Self::Plugin(e) => write!(f, "Plugin error: {e}"),
+            Self::RecursiveMapping => write!(f, "Recursive mapping"),
+            Self::EmptyLayout => write!(f, "There are no windows to save or restore"),
+            Self::NoOtherPane => write!(f, "There is no other pane, start one with :commander"),
+            Self::NoDiff => write!(f, "The windows compared by :diff changed, run it again"),
             Self::NotADummy => write!(f, "Not a DUMMY!"),
         }
     }
@@ -71,3 +89,27 @@ impl From<config::Error> for Error {
         Self::Config(value)
     }
 }
+
+impl From<state::Error> for Error {
+    fn from(value: state::Error) -> Self {
+        Self::State(value)
+    }
+}
+
+impl From<keymap::Error> for Error {
+    fn from(value: keymap::Error) -> Self {
+        Self::Keymap(value)
+    }
+}
+
+impl From<remote::Error> for Error {
+    fn from(value: remote::Error) -> Self {
+        Self::Remote(value)
+    }
+}
+
+impl From<plugin::Error> for Error {
+    fn from(value: plugin::Error) -> Self {
+        Self::Plugin(value)
+    }
+}
@@ -0,0 +1,88 @@
+use std::cmp::Reverse;
+
+use ratatui::layout::Rect;
+
+use super::App;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FocusDirection {
+    Left,
+    Down,
+    Up,
+    Right,
+}
+
+/// How far apart `a` and `b` are in `direction` and how much of their facing edges they share,
+/// `None` when `b` is not in that direction of `a`
+fn distance_and_overlap(a: Rect, b: Rect, direction: FocusDirection) -> Option<(u16, u16)> {
+    let (distance, overlap) = match direction {
+        FocusDirection::Left => (
+            a.x.checked_sub(b.right())?,
+            a.bottom().min(b.bottom()).checked_sub(a.y.max(b.y))?,
+        ),
+        FocusDirection::Right => (
+            b.x.checked_sub(a.right())?,
+            a.bottom().min(b.bottom()).checked_sub(a.y.max(b.y))?,
+        ),
+        FocusDirection::Up => (
+            a.y.checked_sub(b.bottom())?,
+            a.right().min(b.right()).checked_sub(a.x.max(b.x))?,
+        ),
+        FocusDirection::Down => (
+            b.y.checked_sub(a.bottom())?,
+            a.right().min(b.right()).checked_sub(a.x.max(b.x))?,
+        ),
+    };
+
+    (overlap > 0).then_some((distance, overlap))
+}
+
+/// The window next to `focused` in `direction`, the closest one sharing the most of the edge
+fn neighbor(areas: &[(u32, Rect)], focused: u32, direction: FocusDirection) -> Option<u32> {
+    let (_, focused_area) = areas.iter().find(|(id, _)| *id == focused)?;
+
+    areas
+        .iter()
+        .filter(|(id, _)| *id != focused)
+        .filter_map(|(id, area)| {
+            distance_and_overlap(*focused_area, *area, direction)
+                .map(|(distance, overlap)| (id, distance, overlap))
+        })
+        .min_by_key(|(_, distance, overlap)| (*distance, Reverse(*overlap)))
+        .map(|(id, _, _)| *id)
+}
+
+impl App {
+    /// Focuses the window next to the focused one on the screen, as it was last drawn
+    pub fn focus_direction(&mut self, direction: FocusDirection) {
+        let focused = self.window.focused_window_id();
+
+        if let Some(id) = neighbor(&self.window_areas, focused, direction) {
+            self.window.focus_window(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbor_follows_the_screen() {
+        // 1 | 2
+        //   |---
+        //   | 3
+        let areas = [
+            (1, Rect::new(0, 0, 40, 20)),
+            (2, Rect::new(40, 0, 40, 8)),
+            (3, Rect::new(40, 8, 40, 12)),
+        ];
+
+        assert_eq!(neighbor(&areas, 1, FocusDirection::Right), Some(3));
+        assert_eq!(neighbor(&areas, 1, FocusDirection::Left), None);
+        assert_eq!(neighbor(&areas, 2, FocusDirection::Down), Some(3));
+        assert_eq!(neighbor(&areas, 3, FocusDirection::Up), Some(2));
+        assert_eq!(neighbor(&areas, 2, FocusDirection::Left), Some(1));
+        assert_eq!(neighbor(&areas, 3, FocusDirection::Down), None);
+    }
+}
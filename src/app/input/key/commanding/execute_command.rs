@@ -1,54 +1,25 @@
 use crate::{
-    app::{App, InputMode},
-    command::{BooleanOption, Command, SetCommand, SetOption},
+    app::{App, AppEvent},
+    command::{Command, DiffCommand},
+    keymap::Mapping,
+    state::DEFAULT_SESSION,
+    utils::{
+        file::expand_tilde,
+        shell::{expand_placeholders, run_in_shell},
+    },
 };
 
+use super::execute_set::execute_set;
+
 pub fn execute_command(app: &mut App, command: Command) -> bool {
     match command {
-        Command::Set(command) => {
-            let active = match command {
-                SetCommand::Enable(_) => true,
-                SetCommand::Disable(_) => false,
-                SetCommand::Toggle(_) => !app.config.number().active(),
-                SetCommand::Set(option) => match option {
-                    SetOption::HistorySize(size) => {
-                        app.config.mut_history().set_size(size);
-
-                        app.command_history.set_size(size);
-
-                        app.input_mode = InputMode::Normal { precommand: None };
-
-                        return true;
-                    }
-                },
-            };
-
-            match command {
-                SetCommand::Enable(option)
-                | SetCommand::Disable(option)
-                | SetCommand::Toggle(option) => match option {
-                    BooleanOption::Number => {
-                        app.config.mut_number().set_active(active);
-                    }
-                    BooleanOption::RelativeNumber => {
-                        app.config.mut_number().set_relative(active);
-
-                        if active {
-                            app.config.mut_number().set_active(true);
-                        }
-                    }
-                },
-                SetCommand::Set(_) => {
-                    app.input_mode = InputMode::Normal { precommand: None };
-
-                    return false;
-                }
-            }
-
-            app.error = None;
+        Command::Set(commands) => {
+            app.error = execute_set(app, commands).err();
         }
         Command::Quit(command) => {
-            if command.all() {
+            if command.choose_directory() {
+                app.quit_choosing_directory();
+            } else if command.all() {
                 app.quit();
             } else {
                 app.quit_focused_window();
@@ -59,6 +30,68 @@ pub fn execute_command(app: &mut App, command: Command) -> bool {
                 app.error = Some(e.into())
             }
         }
+        Command::Shell(command) => {
+            let context = app.window.focused_context();
+            let command = expand_placeholders(&command, context.as_ref());
+            let directory = context.and_then(|context| context.directory);
+
+            match app
+                .suspend(|| run_in_shell(&command, directory.as_deref(), true))
+                .and_then(|status| Ok(status?))
+            {
+                Ok(_) => {
+                    let _ = app.event_tx().send(AppEvent::Reset);
+                }
+                Err(e) => app.error = Some(e),
+            }
+        }
+        Command::Commander(path) => {
+            app.start_commander(path.as_deref().map(expand_tilde));
+        }
+        Command::Diff(command) => match command {
+            DiffCommand::Start { content } => app.diff(content),
+            DiffCommand::Off => app.diff_off(),
+            DiffCommand::Sync { put } => app.plan_sync(put),
+        },
+        Command::Compress(name) => app.compress(&expand_tilde(&name)),
+        Command::Extract(destination) => app.extract(destination.as_deref().map(expand_tilde)),
+        Command::Cd(path) => {
+            app.change_directory(expand_tilde(path.as_deref().unwrap_or("~")));
+        }
+        Command::Map(command) => match command.rhs().parse() {
+            Ok(keys) => {
+                let mapping = Mapping::Keys {
+                    keys,
+                    noremap: command.noremap(),
+                };
+
+                if let Err(e) = app.map(command.lhs(), mapping) {
+                    app.error = Some(e);
+                }
+            }
+            Err(e) => app.error = Some(e.into()),
+        },
+        Command::Unmap(lhs) => {
+            if let Err(e) = app.unmap(&lhs) {
+                app.error = Some(e);
+            }
+        }
+        Command::Source(path) => app.source(path.as_deref().map(expand_tilde)),
+        Command::Config => app.edit_config(),
+        Command::MakeSession(name) => {
+            let name = name.as_deref().unwrap_or(DEFAULT_SESSION);
+
+            match app.save_session(name) {
+                Ok(_) => app.message = Some(format!("Session \"{name}\" saved")),
+                Err(e) => app.error = Some(e),
+            }
+        }
+        Command::DefineCommand(command) => {
+            app.config.mut_commands().insert(
+                command.name().to_string(),
+                command.replacement().to_string(),
+            );
+        }
     }
 
     true
@@ -0,0 +1,133 @@
+use crate::{
+    app::{App, Result},
+    command::{AssignOperator, SetCommand},
+    config::{self, ConfigOption, OptionKind, OptionValue, find_option},
+};
+
+/// How a bare option name was prefixed, `nonumber` and `invnumber` only work on booleans
+enum Prefix {
+    None,
+    No,
+    Inv,
+}
+
+fn resolve(name: &str) -> config::Result<(&'static ConfigOption, Prefix)> {
+    if let Some(option) = find_option(name) {
+        return Ok((option, Prefix::None));
+    }
+
+    for (prefix, kind) in [("no", Prefix::No), ("inv", Prefix::Inv)] {
+        if let Some(option) = name.strip_prefix(prefix).and_then(find_option) {
+            if option.kind() != OptionKind::Bool {
+                return Err(config::Error::NotABoolean(option.name()));
+            }
+
+            return Ok((option, kind));
+        }
+    }
+
+    Err(config::Error::UnknownOption(name.to_string()))
+}
+
+/// What a `:set` argument did
+enum Applied {
+    /// Asked for the value, formatted to show it
+    Shown(String),
+    /// Assigned a value, which may be the one the option already had
+    Assigned { changed: bool },
+}
+
+fn assign(app: &mut App, option: &ConfigOption, value: OptionValue) -> Applied {
+    let previous = option.get(&app.config);
+    option.set(&mut app.config, value);
+
+    Applied::Assigned {
+        changed: option.get(&app.config) != previous,
+    }
+}
+
+fn toggle(app: &mut App, option: &ConfigOption) -> config::Result<Applied> {
+    match option.get(&app.config) {
+        OptionValue::Bool(value) => Ok(assign(app, option, OptionValue::Bool(!value))),
+        _ => Err(config::Error::NotABoolean(option.name())),
+    }
+}
+
+/// Applies a `:set` argument
+fn apply(app: &mut App, command: SetCommand) -> config::Result<Applied> {
+    Ok(match command {
+        SetCommand::Bare(name) => match resolve(&name)? {
+            (option, Prefix::None) if option.kind() == OptionKind::Bool => {
+                assign(app, option, OptionValue::Bool(true))
+            }
+            (option, Prefix::None) => Applied::Shown(option.format(&option.get(&app.config))),
+            (option, Prefix::No) => assign(app, option, OptionValue::Bool(false)),
+            (option, Prefix::Inv) => toggle(app, option)?,
+        },
+        SetCommand::Toggle(name) => toggle(app, resolve_plain(&name)?)?,
+        SetCommand::Reset(name) => {
+            let option = resolve_plain(&name)?;
+            assign(app, option, option.default_value())
+        }
+        SetCommand::Query(name) => {
+            let option = resolve_plain(&name)?;
+            Applied::Shown(option.format(&option.get(&app.config)))
+        }
+        SetCommand::Assign {
+            name,
+            operator,
+            value,
+        } => {
+            let option = resolve_plain(&name)?;
+            let parsed = option.kind().parse(option, &value)?;
+
+            let value = match operator {
+                AssignOperator::Set => Some(parsed),
+                AssignOperator::Add => option.get(&app.config).add(parsed),
+                AssignOperator::Remove => option.get(&app.config).remove(parsed),
+            }
+            .ok_or(config::Error::UnsupportedOperator {
+                option: option.name(),
+                operator: match operator {
+                    AssignOperator::Add => "+=",
+                    _ => "-=",
+                },
+            })?;
+
+            assign(app, option, value)
+        }
+    })
+}
+
+fn resolve_plain(name: &str) -> config::Result<&'static ConfigOption> {
+    find_option(name).ok_or_else(|| config::Error::UnknownOption(name.to_string()))
+}
+
+pub fn execute_set(app: &mut App, commands: Vec<SetCommand>) -> Result<()> {
+    let mut messages = Vec::new();
+    let mut changed = false;
+    let mut result = Ok(());
+
+    for command in commands {
+        match apply(app, command) {
+            Ok(Applied::Shown(message)) => messages.push(message),
+            Ok(Applied::Assigned { changed: assigned }) => changed |= assigned,
+            Err(e) => {
+                result = Err(e.into());
+                break;
+            }
+        }
+    }
+
+    // options backed by state outside the config are synced after every change
+    if changed {
+        app.history.set_size(app.config.history().size());
+        app.window.reset(&app.config)?;
+    }
+
+    if !messages.is_empty() {
+        app.message = Some(messages.join(" "));
+    }
+
+    result
+}
@@ -1,18 +1,63 @@
 mod execute_command;
+mod execute_set;
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::{
     app::{App, InputMode, widgets::types::InputState},
-    command::parse_command,
+    command::{self, Command, expand_user_command, parse_command},
+    plugin::Plugin,
+    state::HistoryKind,
 };
 
 use execute_command::execute_command;
 
+/// Runs a command line as if it was entered after `:`, user commands included
+pub fn execute_command_line(app: &mut App, line: &str) -> bool {
+    let line = expand_user_command(line, app.config.commands()).unwrap_or_else(|| line.into());
+
+    match resolve_command_line(&line, Plugin::find) {
+        Ok(CommandLine::Builtin(command)) => execute_command(app, command),
+        Ok(CommandLine::Plugin(plugin, args)) => {
+            app.run_plugin(plugin, args);
+            true
+        }
+        Err(e) => {
+            app.error = Some(e.into());
+            true
+        }
+    }
+}
+
+enum CommandLine {
+    Builtin(Command),
+    Plugin(Plugin, Vec<String>),
+}
+
+/// Builtin commands win, the plugins directory is only looked into for lines none of them parse
+fn resolve_command_line(
+    line: &str,
+    find_plugin: impl FnOnce(&str) -> Option<Plugin>,
+) -> command::result::Result<CommandLine> {
+    let error = match parse_command(line) {
+        Ok(command) => return Ok(CommandLine::Builtin(command)),
+        Err(e) => e,
+    };
+
+    let mut words = line.split_whitespace();
+    match words.next().and_then(find_plugin) {
+        Some(plugin) => Ok(CommandLine::Plugin(
+            plugin,
+            words.map(str::to_string).collect(),
+        )),
+        None => Err(error),
+    }
+}
+
 pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
     if let InputMode::Commanding {
         state,
-        current_command,
+        history_index,
         return_state,
     } = &mut app.input_mode
     {
@@ -29,39 +74,30 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
             (KeyModifiers::NONE, KeyCode::Right) => state.right(false),
             (KeyModifiers::SHIFT, KeyCode::Right) => state.right(true),
             (_, KeyCode::Up | KeyCode::Down) => {
-                let diff = if key.code == KeyCode::Up { -1 } else { 1 };
+                let commands = app.history.list(HistoryKind::Command);
+                let prefix = return_state.as_ref().unwrap_or(state).buffer().to_string();
 
-                if *current_command == 0 {
-                    if diff == 1 {
-                        return true;
-                    }
-                    *return_state = Some(state.clone());
+                let found = if key.code == KeyCode::Up {
+                    commands.previous(&prefix, *history_index)
+                } else {
+                    history_index.and_then(|index| commands.next(&prefix, index))
+                };
 
-                    *current_command += diff;
+                match found {
+                    Some(index) => {
+                        if return_state.is_none() {
+                            *return_state = Some(state.clone());
+                        }
 
-                    if let Some(buffer) = app.command_history.get_from_current(*current_command) {
-                        *state = InputState::new(buffer);
-                    } else {
-                        *current_command -= diff;
+                        *history_index = Some(index);
+                        *state = InputState::new(commands.get(index).unwrap_or_default());
                     }
-                } else {
-                    *current_command += diff;
-
-                    if *current_command == 0 {
-                        *state = if let Some(input_state) = return_state {
-                            input_state.clone()
-                        } else {
-                            InputState::new("")
-                        };
-
-                        *return_state = None;
-                    } else if let Some(buffer) =
-                        app.command_history.get_from_current(*current_command)
-                    {
-                        *state = InputState::new(buffer);
-                    } else {
-                        *current_command -= diff;
+                    // going past the newest match brings back the line being typed
+                    None if key.code == KeyCode::Down && history_index.is_some() => {
+                        *state = return_state.take().unwrap_or_default();
+                        *history_index = None;
                     }
+                    None => {}
                 }
             }
             (_, KeyCode::Home) => state.set_cursor_position(0),
@@ -73,19 +109,14 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
                     return true;
                 }
 
-                app.command_history.push(state.buffer().to_string());
-
-                let handled = match parse_command(state.buffer()) {
-                    Ok(command) => execute_command(app, command),
-                    Err(e) => {
-                        app.error = Some(e.into());
-                        true
-                    }
-                };
+                let line = state.buffer().to_string();
+                app.message = None;
+                app.error = app.remember_command(&line).err();
 
+                // Reset first so commands may switch to another mode, like the sync preview
                 app.input_mode = InputMode::Normal { precommand: None };
 
-                return handled;
+                return execute_command_line(app, &line);
             }
             _ => {
                 return false;
@@ -97,3 +128,31 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    use super::*;
+
+    #[test]
+    fn test_builtins_shadow_plugins() {
+        let directory = tempfile::tempdir().unwrap();
+        for name in ["config", "tool"] {
+            let path = directory.path().join(name);
+            fs::write(&path, "#!/bin/sh\n").unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let find = |name: &str| Plugin::find_in(directory.path(), name);
+
+        assert!(matches!(
+            resolve_command_line("config", find),
+            Ok(CommandLine::Builtin(Command::Config))
+        ));
+        assert!(matches!(
+            resolve_command_line("tool a b", find),
+            Ok(CommandLine::Plugin(plugin, args)) if plugin.name() == "tool" && args == ["a", "b"]
+        ));
+        assert!(resolve_command_line("missing", find).is_err());
+    }
+}
@@ -6,6 +6,8 @@ use crate::{
 };
 
 pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
+    let entries_len = get_help_entries_len(&app.keymap);
+
     if let InputMode::Help { selected_index } = &mut app.input_mode {
         match (key.modifiers, key.code) {
             (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => app.quit(),
@@ -13,7 +15,7 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
                 app.input_mode = InputMode::Normal { precommand: None };
             }
             (_, KeyCode::Down | KeyCode::Char('j')) => {
-                if *selected_index + 1 < get_help_entries_len() {
+                if *selected_index + 1 < entries_len {
                     *selected_index = selected_index.saturating_add(1);
                 }
             }
@@ -24,7 +26,7 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
             }
             (_, KeyCode::Home | KeyCode::Char('g')) => *selected_index = 0,
             (_, KeyCode::End | KeyCode::Char('G')) => {
-                *selected_index = get_help_entries_len().saturating_sub(1)
+                *selected_index = entries_len.saturating_sub(1)
             }
             _ => {
                 return false;
@@ -5,9 +5,12 @@ mod normal;
 mod opening;
 mod removing;
 mod renaming;
+mod syncing;
 
 use crossterm::event::{KeyEvent, KeyEventKind};
 
+pub use commanding::execute_command_line;
+
 use crate::app::App;
 
 pub fn handle_key_event(app: &mut App, key: &KeyEvent) -> bool {
@@ -33,5 +36,8 @@ pub fn handle_key_event(app: &mut App, key: &KeyEvent) -> bool {
     if commanding::handle(app, key) {
         return true;
     }
+    if syncing::handle(app, key) {
+        return true;
+    }
     help::handle(app, key)
 }
@@ -1,195 +1,166 @@
-use crate::app::{
-    App, InputMode,
-    precommand::Precommand,
-    widgets::types::InputState,
-    windows::{CommonEntriesWindow, DummyWindow},
-};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::KeyEvent;
 use ratatui::layout::Direction;
 
-pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
-    if let InputMode::Normal { precommand } = &mut app.input_mode {
-        match (key.modifiers, key.code) {
-            (_, KeyCode::Char('q')) => app.quit_focused_window(),
-            (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => app.quit(),
-            (_, KeyCode::Esc) => {
-                if let Err(e) = app.reset() {
-                    app.error = Some(e);
-                }
-            }
-            (KeyModifiers::CONTROL, KeyCode::Char('h')) => {
-                app.input_mode = InputMode::Help { selected_index: 0 };
-            }
-            (KeyModifiers::CONTROL, KeyCode::Char('w')) => match precommand {
-                Some(Precommand::RepeatWindow(count)) => {
-                    let count = *count;
-                    *precommand = None;
-                    for _ in 0..count {
-                        app.next_window();
-                    }
-                }
-                Some(Precommand::Repeat(count)) => {
-                    let count = *count;
-                    *precommand = Some(Precommand::RepeatWindow(count));
-                }
-                _ => {
-                    *precommand = Some(Precommand::RepeatWindow(1));
-                }
-            },
-            (_, KeyCode::Char('h')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = (*count).max(2);
-                    *precommand = None;
-
-                    let window = std::mem::replace(&mut app.window, Box::new(DummyWindow));
-                    app.window = window.split(Direction::Horizontal, count);
-
-                    return true;
-                }
-
-                return false;
-            }
-            (_, KeyCode::Char('v')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = (*count).max(2);
-                    *precommand = None;
-
-                    let window = std::mem::replace(&mut app.window, Box::new(DummyWindow));
-                    app.window = window.split(Direction::Vertical, count);
-
-                    return true;
-                }
-
-                return false;
-            }
-            (_, KeyCode::Right | KeyCode::Char('j')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = *count;
-                    *precommand = None;
-
-                    for _ in 0..count {
-                        app.next_window();
-                    }
-
-                    return true;
-                }
-
-                return false;
-            }
-            (_, KeyCode::Left | KeyCode::Char('k')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = *count;
-                    *precommand = None;
-
-                    for _ in 0..count {
-                        app.prev_window();
-                    }
-
-                    return true;
-                }
+use crate::{
+    app::{
+        App, FocusDirection, InputMode,
+        widgets::types::InputState,
+        windows::{CommonEntriesWindow, DummyWindow},
+    },
+    keymap::{Action, Key, Keymap, Mapping},
+};
 
-                return false;
-            }
-            (_, KeyCode::Char('+')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = count.cast_signed();
-                    *precommand = None;
+pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
+    let InputMode::Normal { precommand } = &mut app.input_mode else {
+        return false;
+    };
+
+    let key = Key::from(key);
+    let precommand = precommand.get_or_insert_default();
+    let keymap = if app.noremap {
+        Keymap::defaults()
+    } else {
+        &app.keymap
+    };
+
+    if precommand.keys().is_empty()
+        && let Some(digit) = key.digit()
+        && keymap.get(&[key]).is_none()
+        && !keymap.is_prefix(&[key])
+    {
+        precommand.push_digit(digit);
+        return true;
+    }
 
-                    app.adjust_window_size(Direction::Horizontal, count);
+    let Some(mappings) = keymap.feed(precommand.mut_keys(), key) else {
+        app.input_mode = InputMode::Normal { precommand: None };
+        return false;
+    };
 
-                    return true;
-                }
+    if mappings.is_empty() {
+        return true;
+    }
 
-                return false;
-            }
-            (_, KeyCode::Char('-')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = count.cast_signed();
-                    *precommand = None;
+    let mut count = precommand.count();
+    app.input_mode = InputMode::Normal { precommand: None };
 
-                    app.adjust_window_size(Direction::Horizontal, -count);
+    // a count only applies to the first mapping, the rest are keys replayed after an ambiguous
+    // sequence was resolved
+    for mapping in mappings {
+        match mapping {
+            Mapping::Action(action) => execute(app, action, count.take()),
+            Mapping::Keys { keys, noremap } => {
+                count = None;
 
-                    return true;
+                if let Err(e) = app.feed_keys(keys.keys(), !noremap) {
+                    app.error = Some(e);
                 }
-
-                return false;
             }
-            (_, KeyCode::Char('=')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = count.cast_signed();
-                    *precommand = None;
-
-                    app.adjust_window_size(Direction::Vertical, count);
+        }
+    }
 
-                    return true;
-                }
+    true
+}
 
-                return false;
+fn execute(app: &mut App, action: Action, count: Option<usize>) {
+    match action {
+        Action::Quit => app.quit_focused_window(),
+        Action::QuitAll => app.quit(),
+        Action::QuitChoosingDirectory => app.quit_choosing_directory(),
+        Action::Reset => {
+            if let Err(e) = app.reset() {
+                app.error = Some(e);
             }
-            (_, KeyCode::Char('_')) => {
-                if let Some(Precommand::RepeatWindow(count)) = precommand {
-                    let count = count.cast_signed();
-                    *precommand = None;
-
-                    app.adjust_window_size(Direction::Vertical, -count);
-
-                    return true;
-                }
-
-                return false;
+        }
+        Action::Help => app.input_mode = InputMode::Help { selected_index: 0 },
+        Action::CommandLine => {
+            app.input_mode = InputMode::Commanding {
+                state: InputState::default(),
+                history_index: None,
+                return_state: None,
+            };
+        }
+        Action::NextWindow => {
+            for _ in 0..count.unwrap_or(1) {
+                app.next_window();
             }
-            (_, KeyCode::Char(':')) => {
-                app.input_mode = InputMode::Commanding {
-                    state: InputState::default(),
-                    current_command: 0,
-                    return_state: None,
-                };
+        }
+        Action::PrevWindow => {
+            for _ in 0..count.unwrap_or(1) {
+                app.prev_window();
             }
-            (_, KeyCode::Char(' ')) => {
-                *precommand = Some(Precommand::Leader);
+        }
+        Action::FocusLeft | Action::FocusDown | Action::FocusUp | Action::FocusRight => {
+            let direction = match action {
+                Action::FocusLeft => FocusDirection::Left,
+                Action::FocusDown => FocusDirection::Down,
+                Action::FocusUp => FocusDirection::Up,
+                _ => FocusDirection::Right,
+            };
+
+            for _ in 0..count.unwrap_or(1) {
+                app.focus_direction(direction);
             }
-            (_, KeyCode::Char('n')) => {
-                if let Some(Precommand::Leader) = precommand {
-                    *precommand = None;
-
-                    let app_window = std::mem::replace(&mut app.window, Box::new(DummyWindow));
-
-                    if let Some(window) = CommonEntriesWindow::toggle(app_window, &app.config) {
-                        app.window = window;
-                    } else {
-                        app.quit();
-                    }
-
-                    return true;
-                }
-
-                return false;
+        }
+        Action::SplitHorizontal | Action::SplitVertical => {
+            let direction = if action == Action::SplitHorizontal {
+                Direction::Horizontal
+            } else {
+                Direction::Vertical
+            };
+
+            let window = std::mem::replace(&mut app.window, Box::new(DummyWindow));
+            app.window = window.split(direction, count.unwrap_or(1).max(2));
+        }
+        Action::IncreaseWidth => {
+            app.adjust_window_size(Direction::Horizontal, resize_amount(count))
+        }
+        Action::DecreaseWidth => {
+            app.adjust_window_size(Direction::Horizontal, -resize_amount(count))
+        }
+        Action::IncreaseHeight => app.adjust_window_size(Direction::Vertical, resize_amount(count)),
+        Action::DecreaseHeight => {
+            app.adjust_window_size(Direction::Vertical, -resize_amount(count))
+        }
+        Action::OnlyWindow => app.only_window(),
+        Action::EqualizeWindows => app.equalize_windows(),
+        Action::MaximizeWidth => app.maximize_window(Direction::Horizontal),
+        Action::MaximizeHeight => app.maximize_window(Direction::Vertical),
+        Action::SwapWindow => {
+            for _ in 0..count.unwrap_or(1) {
+                app.swap_window();
             }
-            (_, KeyCode::Char(c)) => {
-                if c.is_ascii_digit() {
-                    let digit = c.to_digit(10).unwrap() as usize;
-
-                    match precommand {
-                        Some(precommand) => match precommand {
-                            Precommand::Repeat(repeat) => *repeat = *repeat * 10 + digit,
-                            _ => *precommand = Precommand::Repeat(digit),
-                        },
-                        None => *precommand = Some(Precommand::Repeat(digit)),
-                    }
-
-                    return true;
-                }
-
-                return false;
+        }
+        Action::RotateWindows => {
+            for _ in 0..count.unwrap_or(1) {
+                app.rotate_windows();
             }
-            _ => {
-                *precommand = None;
-                return false;
+        }
+        Action::MoveWindowFarLeft => app.move_window_to_edge(Direction::Horizontal, true),
+        Action::MoveWindowFarBottom => app.move_window_to_edge(Direction::Vertical, false),
+        Action::MoveWindowFarTop => app.move_window_to_edge(Direction::Vertical, true),
+        Action::MoveWindowFarRight => app.move_window_to_edge(Direction::Horizontal, false),
+        Action::ToggleCommonEntries => {
+            let app_window = std::mem::replace(&mut app.window, Box::new(DummyWindow));
+
+            if let Some(window) = CommonEntriesWindow::toggle(app_window, &app.config) {
+                app.window = window;
+            } else {
+                app.quit();
             }
         }
-
-        return true;
+        Action::SwitchPane => app.switch_pane(),
+        Action::CopyToOtherPane => app.transfer_to_other_pane(false),
+        Action::MoveToOtherPane => app.transfer_to_other_pane(true),
+        Action::Extract => app.extract(None),
+        Action::Nop => {}
+        _ => {
+            let event_tx = app.event_tx().clone();
+            app.window.handle_action(action, count, &event_tx);
+        }
     }
+}
 
-    false
+fn resize_amount(count: Option<usize>) -> isize {
+    count.unwrap_or(1).cast_signed()
 }
@@ -1,15 +1,15 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::{
-    app::{App, InputMode},
-    utils::file::open_file_with_app,
-};
+use std::path::PathBuf;
+
+use crate::app::{App, InputMode};
 
 pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
     if let InputMode::Opening {
         path,
-        apps,
+        methods,
         selected_index,
+        ..
     } = &mut app.input_mode
     {
         match (key.modifiers, key.code) {
@@ -21,26 +21,33 @@ pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
             (_, KeyCode::Char('j') | KeyCode::Down) => {
                 *selected_index = selected_index
                     .saturating_add(1)
-                    .min(apps.len().saturating_sub(1));
+                    .min(methods.len().saturating_sub(1));
             }
             (_, KeyCode::Char('k') | KeyCode::Up) => {
                 *selected_index = selected_index.saturating_sub(1);
             }
-            (_, KeyCode::Enter) => {
-                if let Some(selected_app) = apps.get(*selected_index) {
-                    match open_file_with_app(selected_app.as_str(), path.as_str()) {
+            (_, KeyCode::Enter | KeyCode::Char('a')) => {
+                if let Some(method) = methods.get(*selected_index).cloned() {
+                    let path = PathBuf::from(path.as_str());
+                    let always = key.code == KeyCode::Char('a');
+
+                    match app
+                        .open_with(&method, &path)
+                        .and_then(|_| app.remember_opening_method(&method, &path, always))
+                    {
                         Ok(_) => {
                             app.input_mode = InputMode::Normal { precommand: None };
                             app.error = None;
                         }
                         Err(e) => {
-                            app.error = Some(e.into());
+                            app.input_mode = InputMode::Normal { precommand: None };
+                            app.error = Some(e);
                         }
                     }
                 }
             }
             (_, KeyCode::Home) => *selected_index = 0,
-            (_, KeyCode::End) => *selected_index = apps.len().saturating_sub(1),
+            (_, KeyCode::End) => *selected_index = methods.len().saturating_sub(1),
             _ => {
                 return false;
             }
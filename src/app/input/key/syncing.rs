@@ -0,0 +1,40 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::{App, InputMode};
+
+pub fn handle(app: &mut App, key: &KeyEvent) -> bool {
+    if let InputMode::Syncing {
+        confirm_selected, ..
+    } = &mut app.input_mode
+    {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => app.quit(),
+            (_, KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N')) => {
+                app.input_mode = InputMode::Normal { precommand: None };
+                app.error = None;
+            }
+            (_, KeyCode::Char('l') | KeyCode::Right) => {
+                *confirm_selected = false;
+            }
+            (_, KeyCode::Char('h') | KeyCode::Left) => {
+                *confirm_selected = true;
+            }
+            (_, KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y')) => {
+                let confirmed = key.code != KeyCode::Enter || *confirm_selected;
+                let mode = std::mem::take(&mut app.input_mode);
+
+                if confirmed && let InputMode::Syncing { plan, .. } = mode {
+                    app.error = None;
+                    app.sync(&plan);
+                }
+            }
+            _ => {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    false
+}
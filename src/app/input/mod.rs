@@ -1,14 +1,17 @@
 mod key;
+mod mouse;
 
 use crossterm::event::Event;
+pub use key::execute_command_line;
 use key::handle_key_event;
+use mouse::handle_mouse_event;
 
 use super::App;
 
 pub fn handle_event(app: &mut App, event: &Event) -> bool {
-    if let Event::Key(key) = event {
-        return handle_key_event(app, key);
+    match event {
+        Event::Key(key) => handle_key_event(app, key),
+        Event::Mouse(mouse) => handle_mouse_event(app, mouse),
+        _ => false,
     }
-
-    false
 }
@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Position;
+
+use crate::app::{App, InputMode, Picker, widgets::title_directory_at};
+
+/// Two clicks on the same cell this close together open the entry
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Remembers the click, returning whether it completes a double click
+fn register_click(app: &mut App, column: u16, row: u16) -> bool {
+    let now = Instant::now();
+    let double_click = app.last_click.is_some_and(|(time, last_column, last_row)| {
+        now.duration_since(time) <= DOUBLE_CLICK_INTERVAL
+            && (last_column, last_row) == (column, row)
+    });
+
+    // a third click starts over instead of opening again
+    app.last_click = (!double_click).then_some((now, column, row));
+
+    double_click
+}
+
+/// Focuses the window under a click and handles a click on the path in its title
+fn click_window(app: &mut App, position: Position) -> bool {
+    let Some((id, area)) = app
+        .window_areas
+        .iter()
+        .find(|(_, area)| area.contains(position))
+        .copied()
+    else {
+        return false;
+    };
+
+    app.window.focus_window(id);
+
+    if position.y != area.y {
+        return false;
+    }
+
+    let directory = app
+        .window
+        .focused_context()
+        .and_then(|context| context.directory);
+    let label = app.picker().map(Picker::label);
+
+    if let Some(directory) = directory
+        && let Some(column) = position.x.checked_sub(area.x + 1)
+        && let Some(target) = title_directory_at(&directory.to_string_lossy(), label, column)
+    {
+        app.change_directory(target);
+    }
+
+    true
+}
+
+pub fn handle_mouse_event(app: &mut App, mouse: &MouseEvent) -> bool {
+    if !matches!(app.input_mode, InputMode::Normal { .. }) {
+        return false;
+    }
+
+    let area = app.window_area;
+    let event_tx = app.event_tx().clone();
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(border) = app.window.border_at(mouse.column, mouse.row, area) {
+                app.dragged_border = Some(border);
+                return true;
+            }
+
+            let double_click = register_click(app, mouse.column, mouse.row);
+
+            if !click_window(app, Position::new(mouse.column, mouse.row)) {
+                app.window
+                    .handle_mouse(mouse, double_click, area, &event_tx);
+            }
+        }
+        MouseEventKind::Drag(MouseButton::Left) => {
+            if let Some((split_id, index)) = app.dragged_border {
+                app.window
+                    .drag_border(split_id, index, mouse.column, mouse.row, area);
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) => app.dragged_border = None,
+        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+            app.window.handle_mouse(mouse, false, area, &event_tx);
+        }
+        _ => return false,
+    }
+
+    true
+}
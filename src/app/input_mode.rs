@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::{directory_entry::SyncCopy, utils::file::OpeningMethod};
+
 use super::{precommand::Precommand, widgets::types::InputState};
 
 #[derive(Debug)]
@@ -19,18 +21,27 @@ pub enum InputMode {
         removing_selected: bool,
     },
     Opening {
-        apps: Vec<String>,
+        methods: Vec<OpeningMethod>,
         path: String,
         selected_index: usize,
+        /// Index of the method used without the modal, if any
+        always_index: Option<usize>,
     },
     Commanding {
         state: InputState,
-        current_command: isize,
+        /// The command history entry shown, `None` while editing a new line
+        history_index: Option<usize>,
+        /// The line typed before browsing the history, its text filters the entries browsed
         return_state: Option<InputState>,
     },
     Help {
         selected_index: usize,
     },
+    /// Previewing the copies of a `:diffput` or `:diffget` before doing them
+    Syncing {
+        plan: Vec<SyncCopy>,
+        confirm_selected: bool,
+    },
 }
 
 impl InputMode {
@@ -54,6 +65,15 @@ impl InputMode {
         }
     }
 
+    pub fn opening_default() -> Self {
+        Self::Opening {
+            methods: Vec::default(),
+            path: String::default(),
+            selected_index: usize::default(),
+            always_index: None,
+        }
+    }
+
     pub fn help_default() -> Self {
         Self::Help {
             selected_index: usize::default(),
@@ -71,6 +91,7 @@ impl Display for InputMode {
             Self::Opening { .. } => write!(f, "Opening"),
             Self::Commanding { .. } => write!(f, "Commanding"),
             Self::Help { .. } => write!(f, "Help"),
+            Self::Syncing { .. } => write!(f, "Syncing"),
         }
     }
 }
@@ -0,0 +1,70 @@
+use std::thread;
+
+use log::error;
+use ratatui::DefaultTerminal;
+
+use super::{App, AppEvent, Result, ui::render_ui};
+
+impl App {
+    /// Runs `job` on another thread, showing `description` until it finishes with the message
+    /// it returns or its error
+    pub fn spawn_job<F>(&mut self, description: String, job: F)
+    where
+        F: FnOnce() -> Result<String> + Send + 'static,
+    {
+        let event_tx = self.event_tx().clone();
+
+        self.running_jobs += 1;
+        self.error = None;
+        self.message = Some(format!("{description}..."));
+
+        thread::spawn(move || {
+            let _ = event_tx.send(AppEvent::JobFinished(job()));
+        });
+    }
+
+    /// Shows how a job ended and lists the entries it changed
+    pub(super) fn finish_job(&mut self, result: Result<String>) {
+        self.running_jobs = self.running_jobs.saturating_sub(1);
+
+        // refreshed either way, a failed job may still have written some entries
+        let refreshed = self.window.reset(&self.config);
+
+        match result.and_then(|message| refreshed.map(|_| message)) {
+            Ok(message) if self.running_jobs > 0 => {
+                self.message = Some(format!("{message}, {} more running", self.running_jobs));
+            }
+            Ok(message) => self.message = Some(message),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Blocks until every job finished, so quitting never leaves an archive half written
+    pub(super) fn wait_for_jobs(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while self.running_jobs > 0 {
+            self.error = None;
+            self.message = Some(format!(
+                "Waiting for {} {} to finish...",
+                self.running_jobs,
+                if self.running_jobs == 1 {
+                    "job"
+                } else {
+                    "jobs"
+                }
+            ));
+            terminal.draw(|frame| render_ui(self, frame))?;
+
+            match self.event_rx.recv() {
+                Ok(AppEvent::JobFinished(Err(e))) => {
+                    error!("{e}");
+                    self.running_jobs -= 1;
+                }
+                Ok(AppEvent::JobFinished(Ok(_))) => self.running_jobs -= 1,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,21 @@
 #[allow(clippy::module_inception)]
 mod app;
 mod app_event;
+mod archive;
+mod commander;
+mod diff;
 mod error;
+mod focus;
 mod input;
 mod input_mode;
+mod jobs;
+mod opening;
+mod picker;
+mod plugins;
 mod precommand;
+mod remote;
 mod result;
+mod session;
 mod ui;
 pub mod widgets;
 mod window;
@@ -14,6 +24,9 @@ mod windows;
 pub use app::App;
 pub use app_event::AppEvent;
 pub use error::Error;
+pub use focus::FocusDirection;
 pub use input_mode::InputMode;
-pub use precommand::Precommand;
+pub use picker::Picker;
 pub use result::Result;
+pub use session::WindowLayout;
+pub use window::WindowContext;
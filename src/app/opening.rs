@@ -0,0 +1,159 @@
+use std::path::Path;
+
+use crate::utils::{
+    desktop_entry::DesktopEntry,
+    file::{
+        FileError, OpeningMethod, get_mime_type, get_opening_methods, is_text_file, open_file,
+        open_file_with_app, open_file_with_command,
+    },
+    xdg_dirs::XdgDirs,
+};
+
+use super::{App, AppEvent, InputMode, Result};
+
+/// What opening choices are remembered by, the MIME type or the extension for types too generic
+/// to tell anything about the file
+fn file_type(path: &Path) -> Option<String> {
+    match get_mime_type(path).ok().as_deref() {
+        None | Some("application/octet-stream" | "application/x-zerosize") => path
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy().to_lowercase())),
+        Some(mime_type) => Some(mime_type.into()),
+    }
+}
+
+impl App {
+    /// Finds the method a remembered key refers to, if it is still around
+    fn resolve_opening_method(&self, key: &str) -> Option<OpeningMethod> {
+        let (kind, value) = key.split_once(':')?;
+
+        match kind {
+            "rule" => self
+                .config
+                .opener()
+                .rules()
+                .iter()
+                .find(|rule| rule.name() == value)
+                .map(|rule| OpeningMethod::Rule(rule.to_owned())),
+            "desktop" => DesktopEntry::find(value, &XdgDirs::from_env())
+                .filter(|entry| entry.is_available())
+                .map(OpeningMethod::Desktop),
+            "app" => Some(OpeningMethod::App(value.into())),
+            _ => None,
+        }
+    }
+
+    fn always_opening_method(&self, path: &Path) -> Option<OpeningMethod> {
+        let file_type = file_type(path)?;
+
+        self.resolve_opening_method(self.opening_choices.always(&file_type)?)
+    }
+
+    fn matching_rules(&self, path: &Path) -> Vec<OpeningMethod> {
+        self.config
+            .opener()
+            .matching_rules(path, || get_mime_type(path).ok())
+            .into_iter()
+            .map(|rule| OpeningMethod::Rule(rule.to_owned()))
+            .collect()
+    }
+
+    /// Opens the file with the method picked to always open its type with, the first matching
+    /// opener rule, the terminal editor if configured for text files or the platform opener
+    pub fn open_file(&mut self, path: &Path) {
+        if let Some(method) = self
+            .always_opening_method(path)
+            .or_else(|| self.matching_rules(path).into_iter().next())
+        {
+            if let Err(e) = self.open_with(&method, path) {
+                self.error = Some(e);
+            }
+        } else if self.config.opener().text_in_editor() && is_text_file(path) {
+            self.edit(path);
+        } else {
+            let _ = open_file(path);
+        }
+    }
+
+    /// Shows the opening modal listing the matching opener rules followed by the platform apps,
+    /// with the remembered choices for the file type on top
+    pub fn show_opening_methods(&mut self, path: &Path) {
+        let mut methods = self.matching_rules(path);
+
+        match get_opening_methods(&path.to_path_buf()) {
+            Ok(apps) => methods.extend(apps),
+            Err(e) => {
+                if methods.is_empty() {
+                    self.error = Some(e.into());
+                    return;
+                }
+            }
+        }
+
+        if methods.is_empty() {
+            self.error = Some(FileError::NoAppsFound.into());
+            return;
+        }
+
+        let file_type = file_type(path);
+        let always = file_type
+            .as_deref()
+            .and_then(|file_type| self.opening_choices.always(file_type));
+        let last = file_type
+            .as_deref()
+            .and_then(|file_type| self.opening_choices.last(file_type));
+
+        for key in [last, always].into_iter().flatten() {
+            if let Some(index) = methods.iter().position(|method| method.key() == key) {
+                let method = methods.remove(index);
+                methods.insert(0, method);
+            }
+        }
+
+        let always_index = always.and_then(|key| methods.iter().position(|m| m.key() == key));
+
+        self.input_mode = InputMode::Opening {
+            methods,
+            path: path.to_string_lossy().to_string(),
+            selected_index: 0,
+            always_index,
+        };
+    }
+
+    /// Remembers the method for the type of the file, `always` makes it the one used when opening
+    /// without the modal
+    pub fn remember_opening_method(
+        &mut self,
+        method: &OpeningMethod,
+        path: &Path,
+        always: bool,
+    ) -> Result<()> {
+        if let Some(file_type) = file_type(path) {
+            self.opening_choices
+                .remember(&file_type, &method.key(), always);
+            self.opening_choices.save()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn open_with(&mut self, method: &OpeningMethod, path: &Path) -> Result<()> {
+        match method {
+            OpeningMethod::Rule(rule) if rule.foreground() => {
+                self.suspend(|| open_file_with_command(rule.command(), path, true))??;
+
+                let _ = self.event_tx().send(AppEvent::Reset);
+            }
+            OpeningMethod::Rule(rule) => open_file_with_command(rule.command(), path, false)?,
+            OpeningMethod::Desktop(entry) if entry.terminal() => {
+                self.suspend(|| entry.launch(&[path]))??;
+
+                let _ = self.event_tx().send(AppEvent::Reset);
+            }
+            OpeningMethod::Desktop(entry) => entry.launch(&[path])?,
+            OpeningMethod::App(app) => open_file_with_app(app, path.to_string_lossy().as_ref())?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use super::App;
+
+/// File picker mode of `--choosefile` and `--choosefiles`, opening a file picks it instead
+pub struct Picker {
+    output: File,
+    multiple: bool,
+    separator: u8,
+}
+
+impl Picker {
+    /// `multiple` picks the marked entries when there are any, `null` separates paths with NUL
+    /// instead of newlines
+    pub fn new(output: File, multiple: bool, null: bool) -> Self {
+        Self {
+            output,
+            multiple,
+            separator: if null { b'\0' } else { b'\n' },
+        }
+    }
+
+    pub fn multiple(&self) -> bool {
+        self.multiple
+    }
+
+    /// Shown in the window titles while picking
+    pub fn label(&self) -> &'static str {
+        if self.multiple {
+            " PICK FILES "
+        } else {
+            " PICK FILE "
+        }
+    }
+
+    /// Writes every path followed by the separator
+    pub fn write(&mut self, paths: &[PathBuf]) -> io::Result<()> {
+        for path in paths {
+            self.output.write_all(path.as_os_str().as_encoded_bytes())?;
+            self.output.write_all(&[self.separator])?;
+        }
+
+        self.output.flush()
+    }
+}
+
+impl App {
+    /// Picks the marked entries of the focused window, or `path` when nothing is marked or only
+    /// one file is picked, returning false when `path` is a directory to navigate into, marks or
+    /// not
+    pub fn pick(&mut self, path: &Path) -> bool {
+        let Some(picker) = &self.picker else {
+            return false;
+        };
+        if path.is_dir() {
+            return false;
+        }
+
+        let marked = self
+            .window
+            .focused_context()
+            .map(|context| context.marked)
+            .unwrap_or_default();
+
+        self.picked = if picker.multiple() && !marked.is_empty() {
+            marked
+        } else {
+            vec![path.to_owned()]
+        };
+
+        self.quit();
+
+        true
+    }
+
+    pub fn set_picker(&mut self, picker: Picker) {
+        self.picker = Some(picker);
+    }
+
+    pub fn picker(&self) -> Option<&Picker> {
+        self.picker.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crossbeam::channel::unbounded;
+
+    use super::*;
+    use crate::{config::Config, keymap::Action};
+
+    #[test]
+    fn test_write_separates_paths() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("picked");
+
+        let mut picker = Picker::new(File::create(&path).unwrap(), true, true);
+        picker
+            .write(&[PathBuf::from("/a b"), PathBuf::from("/c")])
+            .unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"/a b\0/c\0");
+    }
+
+    #[test]
+    fn test_directories_are_entered_with_marks() {
+        let directory = tempfile::tempdir().unwrap();
+        fs::create_dir(directory.path().join("a")).unwrap();
+        fs::write(directory.path().join("b"), "").unwrap();
+        let (mut app, _) = App::new(
+            directory.path().to_string_lossy().as_ref(),
+            Config::default(),
+            PathBuf::new(),
+        )
+        .unwrap();
+        app.set_picker(Picker::new(
+            File::create(directory.path().join("picked")).unwrap(),
+            true,
+            false,
+        ));
+
+        let (event_tx, _event_rx) = unbounded();
+        app.window.handle_action(Action::Mark, None, &event_tx);
+        app.window.handle_action(Action::Mark, None, &event_tx);
+
+        assert!(!app.pick(&directory.path().join("a")));
+        assert!(app.pick(&directory.path().join("b")));
+        assert_eq!(app.picked.len(), 2);
+    }
+}
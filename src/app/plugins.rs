@@ -0,0 +1,65 @@
+use std::thread;
+
+use serde_json::json;
+
+use crate::{
+    directory_entry::DirectoryEntry,
+    plugin::{Plugin, PluginAction},
+};
+
+use super::{App, AppEvent};
+
+impl App {
+    /// Runs `plugin` in the background with the focused window as context on its stdin and
+    /// turns the actions it replies with into events
+    pub fn run_plugin(&mut self, plugin: Plugin, args: Vec<String>) {
+        let context = self.window.focused_context().unwrap_or_default();
+        let input = json!({
+            "window_id": context.id,
+            "directory": context.directory,
+            "selected": context.selected,
+            "marked": context.marked,
+            "args": args,
+        });
+        let socket = self.socket_path.clone();
+        let event_tx = self.event_tx().clone();
+
+        thread::spawn(move || {
+            let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+            let actions = match plugin.run(
+                &args,
+                context.directory.as_deref(),
+                &input,
+                socket.as_deref(),
+            ) {
+                Ok(actions) => actions,
+                Err(e) => {
+                    let _ = event_tx.send(AppEvent::SetError(Some(e.into())));
+                    return;
+                }
+            };
+
+            for action in actions {
+                let event = match action {
+                    PluginAction::Cd { path } => AppEvent::ChangeDirectory(path),
+                    PluginAction::Select { path } => AppEvent::Select(path),
+                    PluginAction::Refresh => AppEvent::Reset,
+                    PluginAction::Message { text } => AppEvent::Message(text),
+                    PluginAction::Open { path } => match DirectoryEntry::try_from(&path) {
+                        Ok(entry) => AppEvent::Open {
+                            entry_type: entry.entry_type().clone(),
+                            path,
+                            new: false,
+                        },
+                        Err(e) => AppEvent::SetError(Some(e.into())),
+                    },
+                };
+
+                if event_tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
@@ -1,6 +1,48 @@
-#[derive(Debug)]
-pub enum Precommand {
-    Leader,
-    Repeat(usize),
-    RepeatWindow(usize),
+use std::fmt::Display;
+
+use crate::keymap::{Key, KeySequence};
+
+/// What has been typed in normal mode before a binding completes: an optional `{n}` count and the
+/// keys of a partially typed sequence
+#[derive(Debug, Default)]
+pub struct Precommand {
+    count: Option<usize>,
+    keys: Vec<Key>,
+}
+
+impl Precommand {
+    pub fn count(&self) -> Option<usize> {
+        self.count
+    }
+
+    pub fn push_digit(&mut self, digit: usize) {
+        self.count = Some(
+            self.count
+                .unwrap_or(0)
+                .saturating_mul(10)
+                .saturating_add(digit),
+        );
+    }
+
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    pub fn mut_keys(&mut self) -> &mut Vec<Key> {
+        &mut self.keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count.is_none() && self.keys.is_empty()
+    }
+}
+
+impl Display for Precommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(count) = self.count {
+            write!(f, "{count}")?;
+        }
+
+        write!(f, "{}", KeySequence::from(self.keys.clone()))
+    }
 }
@@ -0,0 +1,62 @@
+use serde_json::{Map, Value, json};
+
+use crate::remote::{Query, Request, error_response};
+
+use super::App;
+
+impl App {
+    /// Runs a command sent over the socket or answers a query about the focused window
+    pub fn answer(&mut self, request: Request) -> Value {
+        match request {
+            Request::Command(line) => {
+                self.error = None;
+                self.execute_command_line(&line);
+
+                match &self.error {
+                    Some(e) => error_response(&e.to_string()),
+                    None => json!({ "ok": true }),
+                }
+            }
+            Request::Query(query) => {
+                let context = self.window.focused_context().unwrap_or_default();
+                let mut answer = Map::new();
+                answer.insert("ok".into(), true.into());
+
+                if matches!(query, Query::Directory | Query::Context) {
+                    answer.insert(
+                        "directory".into(),
+                        json!(
+                            context
+                                .directory
+                                .map(|path| path.to_string_lossy().to_string())
+                        ),
+                    );
+                }
+                if matches!(query, Query::Selected | Query::Context) {
+                    answer.insert(
+                        "selected".into(),
+                        json!(
+                            context
+                                .selected
+                                .map(|path| path.to_string_lossy().to_string())
+                        ),
+                    );
+                }
+                if matches!(query, Query::Marked | Query::Context) {
+                    answer.insert(
+                        "marked".into(),
+                        json!(
+                            context
+                                .marked
+                                .iter()
+                                .map(|path| path.to_string_lossy())
+                                .collect::<Vec<_>>()
+                        ),
+                    );
+                }
+
+                Value::Object(answer)
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::path::PathBuf;
+
+use ratatui::layout::Direction;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    state::{self, Session},
+};
+
+use super::{
+    App, Error, Result,
+    window::{Window, WindowSize, generate_window_id},
+    windows::{CommonEntriesWindow, FileManagerWindow, SplitWindow},
+};
+
+/// `Direction` is not serializable, it is written as `horizontal` or `vertical`
+mod direction {
+    use ratatui::layout::Direction;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(
+        direction: &Direction,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match direction {
+            Direction::Horizontal => "horizontal",
+            Direction::Vertical => "vertical",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Direction, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "horizontal" => Ok(Direction::Horizontal),
+            "vertical" => Ok(Direction::Vertical),
+            other => Err(D::Error::unknown_variant(
+                other,
+                &["horizontal", "vertical"],
+            )),
+        }
+    }
+}
+
+/// A window tree without its entries, what a session remembers
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum WindowLayout {
+    Split {
+        #[serde(with = "direction")]
+        direction: Direction,
+        focused_index: usize,
+        window_size: WindowSize,
+        windows: Vec<WindowLayout>,
+        /// Left out for splits that are not commander mode
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        commander: bool,
+    },
+    FileManager {
+        directory: PathBuf,
+        selected: Option<PathBuf>,
+        window_size: WindowSize,
+    },
+    CommonEntries {
+        selected_index: usize,
+        window_size: WindowSize,
+    },
+}
+
+impl WindowLayout {
+    /// Rebuilds the windows, leaving out the ones whose directory is gone
+    pub fn build(self, config: &Config) -> Result<Box<dyn Window>> {
+        match self {
+            Self::Split {
+                direction,
+                focused_index,
+                window_size,
+                windows: layouts,
+                commander,
+            } => {
+                let mut windows = Vec::with_capacity(layouts.len());
+                let mut error = None;
+
+                for layout in layouts {
+                    match layout.build(config) {
+                        Ok(window) => windows.push(window),
+                        Err(e) => error = Some(e),
+                    }
+                }
+
+                match windows.len() {
+                    0 => Err(error.unwrap_or(Error::EmptyLayout)),
+                    1 => Ok(windows.remove(0)),
+                    _ => Ok(Box::new(
+                        SplitWindow::with_focused_index_and_window_size(
+                            direction,
+                            windows,
+                            focused_index,
+                            window_size,
+                        )
+                        .with_commander(commander),
+                    )),
+                }
+            }
+            Self::FileManager {
+                directory,
+                selected,
+                window_size,
+            } => {
+                let mut window = FileManagerWindow::with_id_and_window_size(
+                    directory.to_string_lossy().as_ref(),
+                    generate_window_id(),
+                    window_size,
+                )?;
+
+                if let Some(selected) = selected {
+                    window.select_path(&selected);
+                }
+
+                Ok(Box::new(window))
+            }
+            Self::CommonEntries {
+                selected_index,
+                window_size,
+            } => Ok(Box::new(
+                CommonEntriesWindow::with_selected_index_and_window_size(
+                    config,
+                    selected_index,
+                    window_size,
+                ),
+            )),
+        }
+    }
+}
+
+impl App {
+    /// Writes the window layout to the session `name`
+    pub fn save_session(&self, name: &str) -> Result<()> {
+        let layout = self.window.layout().ok_or(Error::EmptyLayout)?;
+
+        Session::new(layout).save(name)?;
+
+        Ok(())
+    }
+
+    /// What is saved as the last session on quit, the windows or the last one closed
+    pub(super) fn last_session(&mut self) -> Option<Session> {
+        self.last_layout
+            .take()
+            .or_else(|| self.window.layout())
+            .map(Session::new)
+    }
+
+    /// Replaces the windows with the ones of the session `name`
+    pub fn restore_session(&mut self, name: &str) -> Result<()> {
+        self.window = Session::load(name)?.layout().build(&self.config)?;
+
+        Ok(())
+    }
+
+    /// Like `restore_session`, without complaining about a session that was never saved
+    pub fn restore_session_if_saved(&mut self, name: &str) -> Result<()> {
+        match self.restore_session(name) {
+            Err(Error::State(state::Error::SessionNotFound(_))) => Ok(()),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_round_trip() {
+        let layout = WindowLayout::Split {
+            direction: Direction::Horizontal,
+            focused_index: 1,
+            window_size: WindowSize::Default,
+            windows: vec![
+                WindowLayout::CommonEntries {
+                    selected_index: 2,
+                    window_size: WindowSize::AdjustedSize(40, -3),
+                },
+                WindowLayout::FileManager {
+                    directory: PathBuf::from("/tmp"),
+                    selected: Some(PathBuf::from("/tmp/a")),
+                    window_size: WindowSize::Adjusted(4),
+                },
+            ],
+            commander: true,
+        };
+        let session = Session::new(layout);
+
+        let contents = toml::to_string(&session).unwrap();
+        let loaded: Session = toml::from_str(&contents).unwrap();
+
+        assert_eq!(format!("{loaded:?}"), format!("{session:?}"));
+    }
+
+    #[test]
+    fn test_closing_the_last_window_keeps_its_layout() {
+        let directory = tempfile::tempdir().unwrap();
+        let (mut app, _) = App::new(
+            directory.path().to_string_lossy().as_ref(),
+            Config::default(),
+            PathBuf::new(),
+        )
+        .unwrap();
+
+        app.quit_focused_window();
+
+        let session = app.last_session().expect("the closed window is saved");
+        assert!(matches!(
+            session.layout(),
+            WindowLayout::FileManager { directory: saved, .. } if saved == directory.path()
+        ));
+    }
+
+    #[test]
+    fn test_build_skips_missing_directories() {
+        let directory = tempfile::tempdir().unwrap();
+        let selected = directory.path().join("b");
+        std::fs::create_dir(directory.path().join("a")).unwrap();
+        std::fs::create_dir(&selected).unwrap();
+
+        let layout = WindowLayout::Split {
+            direction: Direction::Vertical,
+            focused_index: 0,
+            window_size: WindowSize::Default,
+            windows: vec![
+                WindowLayout::FileManager {
+                    directory: directory.path().join("gone"),
+                    selected: None,
+                    window_size: WindowSize::Default,
+                },
+                WindowLayout::FileManager {
+                    directory: directory.path().to_owned(),
+                    selected: Some(selected.clone()),
+                    window_size: WindowSize::Adjusted(2),
+                },
+            ],
+            commander: false,
+        };
+
+        let window = layout.build(&Config::default()).unwrap();
+
+        assert!(window.as_any().is::<FileManagerWindow>());
+        assert_eq!(window.focused_context().unwrap().selected, Some(selected));
+    }
+}
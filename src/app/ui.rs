@@ -8,8 +8,11 @@ use crate::app::widgets::render_command;
 use super::{
     App, InputMode,
     widgets::{
-        modals::{show_help_modal, show_input_modal, show_opening_modal, show_yes_no_modal},
-        render_error, render_instructions, render_precommand,
+        modals::{
+            show_help_modal, show_input_modal, show_opening_modal, show_sync_modal,
+            show_yes_no_modal,
+        },
+        render_error, render_instructions, render_message, render_precommand,
     },
 };
 
@@ -19,6 +22,8 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
         .constraints(vec![Constraint::Fill(1), Constraint::Length(1)])
         .split(frame.area());
 
+    app.window_area = layout[0];
+    app.window_areas = app.window.window_areas(layout[0]);
     app.window.render(app, frame, layout[0], true);
 
     if let InputMode::Normal {
@@ -30,6 +35,8 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
         render_command(frame, layout[1], state);
     } else if let Some(e) = &app.error {
         render_error(frame, layout[1], e);
+    } else if let Some(message) = &app.message {
+        render_message(frame, layout[1], message);
     } else {
         render_instructions(frame, layout[1]);
     }
@@ -51,14 +58,21 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
             );
         }
         InputMode::Opening {
-            apps,
+            methods,
             path,
             selected_index,
+            always_index,
         } => {
-            show_opening_modal(path, apps, frame, *selected_index);
+            show_opening_modal(path, methods, frame, *selected_index, *always_index);
         }
         InputMode::Help { selected_index } => {
-            show_help_modal(frame, *selected_index);
+            show_help_modal(frame, *selected_index, &app.keymap);
+        }
+        InputMode::Syncing {
+            plan,
+            confirm_selected,
+        } => {
+            show_sync_modal(plan, frame, *confirm_selected);
         }
         _ => {}
     }
@@ -1,3 +1,8 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
 use ratatui::{
     Frame,
     layout::{Constraint, Rect},
@@ -6,16 +11,32 @@ use ratatui::{
     widgets::{Block, Cell, Row, Table, TableState},
 };
 
-use crate::{config::Config, directory_entry::DirectoryEntry};
+use crate::{
+    config::Config,
+    directory_entry::{DiffStatus, DirectoryEntry},
+};
+
+/// How the entries of a table are highlighted
+pub struct EntriesHighlight<'a> {
+    pub selected_index: usize,
+    pub marked: &'a HashSet<PathBuf>,
+    /// The status of each entry while the window is compared by `:diff`
+    pub diff: Option<&'a HashMap<PathBuf, DiffStatus>>,
+}
 
 pub fn draw_entries_table(
     frame: &mut Frame,
     area: Rect,
     entries: &[DirectoryEntry],
-    selected_index: usize,
+    highlight: EntriesHighlight,
     block: Block,
     config: &Config,
 ) {
+    let EntriesHighlight {
+        selected_index,
+        marked,
+        diff,
+    } = highlight;
     let entries_len_digits = entries.len().checked_ilog10().unwrap_or(0) + 1;
 
     let rows: Vec<Row> = entries
@@ -57,14 +78,29 @@ pub fn draw_entries_table(
             cells.push(Cell::from(entry.name()));
 
             if area.width >= 36 {
-                cells.push(Cell::from(entry.formatted_size().unwrap_or_default()));
+                cells.push(Cell::from(
+                    entry
+                        .formatted_size(config.display().size_units())
+                        .unwrap_or_default(),
+                ));
             }
 
             if area.width >= 54 {
                 cells.push(Cell::from(entry.formatted_modified().unwrap_or_default()));
             }
 
-            Row::new(cells)
+            let row = Row::new(cells);
+
+            if marked.contains(entry.path()) {
+                return row.style(Style::default().fg(Color::Yellow).bold());
+            }
+
+            match diff.and_then(|diff| diff.get(entry.path())) {
+                Some(DiffStatus::OnlyLeft) => row.style(Style::default().fg(Color::Green)),
+                Some(DiffStatus::OnlyRight) => row.style(Style::default().fg(Color::Blue)),
+                Some(DiffStatus::Different) => row.style(Style::default().fg(Color::Magenta)),
+                Some(DiffStatus::Same) | None => row,
+            }
         })
         .collect();
 
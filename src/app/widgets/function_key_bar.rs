@@ -0,0 +1,44 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Stylize,
+    text::{Line, Span},
+};
+
+use crate::keymap::{Action, KeySequence, Keymap};
+
+/// What the bar under commander mode offers, in order
+const BAR_ACTIONS: [(Action, &str); 8] = [
+    (Action::SwitchPane, "Switch"),
+    (Action::Edit, "Edit"),
+    (Action::CopyToOtherPane, "Copy"),
+    (Action::MoveToOtherPane, "Move"),
+    (Action::Add, "Add"),
+    (Action::Remove, "Delete"),
+    (Action::Mark, "Mark"),
+    (Action::QuitAll, "Quit"),
+];
+
+/// The binding shown for `action`, a single function or named key when there is one
+fn shown_sequence(keymap: &Keymap, action: Action) -> Option<&KeySequence> {
+    keymap
+        .sequences_for(action)
+        .find(
+            |sequence| matches!(sequence.keys(), [key] if !matches!(key.code(), KeyCode::Char(_))),
+        )
+        .or_else(|| keymap.sequences_for(action).next())
+}
+
+pub fn render_function_key_bar(frame: &mut Frame, area: Rect, keymap: &Keymap) {
+    let mut spans: Vec<Span> = Vec::new();
+
+    for (action, label) in BAR_ACTIONS {
+        if let Some(sequence) = shown_sequence(keymap, action) {
+            spans.push(format!(" {sequence}").blue().bold());
+            spans.push(format!(" {label} ").into());
+        }
+    }
+
+    frame.render_widget(Line::from(spans), area);
+}
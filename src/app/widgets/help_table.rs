@@ -7,47 +7,66 @@ use ratatui::{
 };
 
 use crate::{
-    data::help::get_help,
+    app::InputMode,
+    data::help::{NORMAL_MODE_DESCRIPTION, get_help},
+    keymap::Keymap,
     utils::{format_keybindings, str::wrap_text},
 };
 
-pub fn get_help_table<'a>(width: u16) -> Table<'a> {
+pub fn get_help_table<'a>(width: u16, keymap: &Keymap) -> Table<'a> {
     let description_width = width.saturating_sub(36);
 
-    let help = get_help();
-
     let mut rows: Vec<Row> = Vec::new();
 
-    for mode in help {
-        let (height, description) = wrap_text(mode.description(), description_width);
-
-        rows.push(
-            Row::new(vec![
-                Cell::from(format!("{}", mode.mode()))
-                    .style(Style::default().fg(Color::Green).bold()),
-                Cell::from(""),
-                Cell::from(description),
-            ])
-            .height(height.try_into().unwrap_or(1)),
+    push_mode_row(
+        &mut rows,
+        &InputMode::default(),
+        NORMAL_MODE_DESCRIPTION,
+        description_width,
+    );
+
+    for action in keymap.bound_actions() {
+        let count = if action.takes_count() { "{n}" } else { "" };
+        let keys = keymap
+            .sequences_for(action)
+            .map(|sequence| format!("{count}{sequence}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        push_binding_row(
+            &mut rows,
+            action.name(),
+            &keys,
+            action.description(),
+            description_width,
+        );
+    }
+
+    for (sequence, keys) in keymap.key_mappings() {
+        push_binding_row(
+            &mut rows,
+            "mapping",
+            &sequence.to_string(),
+            &format!("Types {keys}"),
+            description_width,
+        );
+    }
+
+    for mode in get_help() {
+        push_mode_row(
+            &mut rows,
+            mode.mode(),
+            mode.description(),
+            description_width,
         );
 
         for item in mode.items() {
-            let (description_height, description) =
-                wrap_text(item.description(), description_width);
-            let (keybindings_height, keybindings) =
-                wrap_text(&format_keybindings(item.keys(), item.precommand()), 16);
-
-            rows.push(
-                Row::new(vec![
-                    Cell::from(item.name()),
-                    Cell::from(keybindings).style(Style::default().fg(Color::Blue).bold()),
-                    Cell::from(description),
-                ])
-                .height(
-                    max(description_height, keybindings_height)
-                        .try_into()
-                        .unwrap_or(1),
-                ),
+            push_binding_row(
+                &mut rows,
+                item.name(),
+                &format_keybindings(item.keys()),
+                item.description(),
+                description_width,
             );
         }
     }
@@ -60,3 +79,40 @@ pub fn get_help_table<'a>(width: u16) -> Table<'a> {
 
     Table::new(rows, widths).row_highlight_style(Style::default().reversed().bold())
 }
+
+fn push_mode_row(rows: &mut Vec<Row>, mode: &InputMode, description: &str, description_width: u16) {
+    let (height, description) = wrap_text(description, description_width);
+
+    rows.push(
+        Row::new(vec![
+            Cell::from(format!("{mode}")).style(Style::default().fg(Color::Green).bold()),
+            Cell::from(""),
+            Cell::from(description),
+        ])
+        .height(height.try_into().unwrap_or(1)),
+    );
+}
+
+fn push_binding_row(
+    rows: &mut Vec<Row>,
+    name: &str,
+    keys: &str,
+    description: &str,
+    description_width: u16,
+) {
+    let (description_height, description) = wrap_text(description, description_width);
+    let (keybindings_height, keybindings) = wrap_text(keys, 16);
+
+    rows.push(
+        Row::new(vec![
+            Cell::from(name.to_string()),
+            Cell::from(keybindings).style(Style::default().fg(Color::Blue).bold()),
+            Cell::from(description),
+        ])
+        .height(
+            max(description_height, keybindings_height)
+                .try_into()
+                .unwrap_or(1),
+        ),
+    );
+}
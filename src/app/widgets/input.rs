@@ -10,7 +10,7 @@ use crate::{
     utils::str::{all_but_first, first_char_str},
 };
 
-pub fn get_input(state: &InputState) -> Paragraph {
+pub fn get_input(state: &InputState) -> Paragraph<'_> {
     let mut spans = Vec::new();
 
     let cursor_position = state.cursor_position().min(state.buffer().len());
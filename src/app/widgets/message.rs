@@ -0,0 +1,5 @@
+use ratatui::{Frame, layout::Rect, text::Line};
+
+pub fn render_message(frame: &mut Frame, area: Rect, message: &str) {
+    frame.render_widget(Line::from(format!(" {message} ")), area);
+}
@@ -2,9 +2,11 @@ mod centered_rect;
 mod command;
 mod entries_table;
 mod error;
+mod function_key_bar;
 mod help_table;
 mod input;
 mod instructions;
+mod message;
 mod minimal_entries_table;
 pub mod modals;
 mod precommand;
@@ -12,11 +14,13 @@ mod title;
 pub mod types;
 
 pub use command::render_command;
-pub use entries_table::draw_entries_table;
+pub use entries_table::{EntriesHighlight, draw_entries_table};
 pub use error::render_error;
+pub use function_key_bar::render_function_key_bar;
 pub use help_table::get_help_table;
 pub use input::get_input;
 pub use instructions::render_instructions;
+pub use message::render_message;
 pub use minimal_entries_table::draw_minimal_entries_table;
 pub use precommand::render_precommand;
-pub use title::add_title_to_block;
+pub use title::{add_title_to_block, title_directory_at};
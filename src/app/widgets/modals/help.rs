@@ -4,9 +4,12 @@ use ratatui::{
     widgets::{Block, Borders, Clear, TableState},
 };
 
-use crate::app::widgets::{centered_rect::get_centered_rect_by_percent, get_help_table};
+use crate::{
+    app::widgets::{centered_rect::get_centered_rect_by_percent, get_help_table},
+    keymap::Keymap,
+};
 
-pub fn show_help_modal(frame: &mut Frame, selected_index: usize) {
+pub fn show_help_modal(frame: &mut Frame, selected_index: usize, keymap: &Keymap) {
     let area = get_centered_rect_by_percent(80, 70, frame.area());
 
     let block = Block::default()
@@ -14,7 +17,7 @@ pub fn show_help_modal(frame: &mut Frame, selected_index: usize) {
         .borders(Borders::ALL)
         .style(Style::default().fg(Color::Yellow).bg(Color::Black));
 
-    let table = get_help_table(area.width).block(block);
+    let table = get_help_table(area.width, keymap).block(block);
 
     let mut state = TableState::default();
     state.select(Some(selected_index));
@@ -1,9 +1,11 @@
 mod help;
 mod input;
 mod opening;
+mod sync;
 mod yes_no;
 
 pub use help::show_help_modal;
 pub use input::show_input_modal;
 pub use opening::show_opening_modal;
+pub use sync::show_sync_modal;
 pub use yes_no::show_yes_no_modal;
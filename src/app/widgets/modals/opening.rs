@@ -5,12 +5,18 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Clear, Row, Table, TableState},
 };
 
-use crate::app::widgets::centered_rect::get_centered_rect;
+use crate::{app::widgets::centered_rect::get_centered_rect, utils::file::OpeningMethod};
 
-pub fn show_opening_modal(file: &str, apps: &[String], frame: &mut Frame, selected_index: usize) {
+pub fn show_opening_modal(
+    file: &str,
+    methods: &[OpeningMethod],
+    frame: &mut Frame,
+    selected_index: usize,
+    always_index: Option<usize>,
+) {
     let area = get_centered_rect(
         40,
-        (apps.len().saturating_add(2))
+        (methods.len().saturating_add(2))
             .try_into()
             .unwrap_or(10)
             .min(10),
@@ -19,12 +25,21 @@ pub fn show_opening_modal(file: &str, apps: &[String], frame: &mut Frame, select
 
     let block = Block::default()
         .title(format!("Opening {file}"))
+        .title_bottom(" <Enter> open  <a> always open with this ")
         .borders(Borders::ALL)
         .style(Style::default().fg(Color::Yellow).bg(Color::Black));
 
-    let rows: Vec<Row> = apps
+    let rows: Vec<Row> = methods
         .iter()
-        .map(|app| Row::new(vec![Cell::from(app.as_str())]))
+        .enumerate()
+        .map(|(i, method)| {
+            if Some(i) == always_index {
+                Row::new(vec![Cell::from(format!("{} (always)", method.name()))])
+                    .style(Style::default().bold())
+            } else {
+                Row::new(vec![Cell::from(method.name())])
+            }
+        })
         .collect();
 
     let table = Table::new(rows, vec![Constraint::Fill(1)])
@@ -0,0 +1,78 @@
+use ratatui::{
+    Frame,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List},
+};
+
+use crate::{app::widgets::centered_rect::get_centered_rect, directory_entry::SyncCopy};
+
+const MAX_HEIGHT: usize = 20;
+
+/// Lists the copies of a sync, as a dry run, and asks to do them
+pub fn show_sync_modal(plan: &[SyncCopy], frame: &mut Frame, selected: bool) {
+    let area = get_centered_rect(
+        60,
+        plan.len()
+            .saturating_add(2)
+            .min(MAX_HEIGHT)
+            .try_into()
+            .unwrap_or(10),
+        frame.area(),
+    );
+
+    let (yes_style, no_style) = if selected {
+        (
+            Style::default().fg(Color::Black).bg(Color::Green).bold(),
+            Style::default().fg(Color::White),
+        )
+    } else {
+        (
+            Style::default().fg(Color::White),
+            Style::default().fg(Color::Black).bg(Color::Red).bold(),
+        )
+    };
+
+    let title = match plan.first().and_then(|copy| copy.destination.parent()) {
+        Some(directory) => format!(
+            "Sync {} {} into {}",
+            plan.len(),
+            if plan.len() == 1 { "entry" } else { "entries" },
+            directory.display()
+        ),
+        None => "Sync".to_string(),
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_bottom(
+            Line::from(vec![
+                Span::styled(" [Yes] ", yes_style),
+                Span::raw("  "),
+                Span::styled(" [No] ", no_style),
+            ])
+            .centered(),
+        )
+        .borders(Borders::ALL)
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+
+    let items: Vec<Line> = plan
+        .iter()
+        .map(|copy| {
+            let name = copy
+                .source
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_default();
+
+            if copy.replace {
+                Line::from(vec![Span::raw("replace "), Span::raw(name).magenta()])
+            } else {
+                Line::from(vec![Span::raw("copy    "), Span::raw(name).green()])
+            }
+        })
+        .collect();
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(List::new(items).block(block), area);
+}
@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use ratatui::{
     style::{Color, Style, Stylize},
     text::{Line, Span},
@@ -28,13 +30,25 @@ pub fn get_title<'a>(path: String) -> impl Into<Title<'a>> {
     Line::from(title_items).bold()
 }
 
-pub fn add_title_to_block<'a>(directory: &'a str, block: Block<'a>) -> Block<'a> {
+/// `label` follows the app name in a color of its own, e.g. while picking files
+fn title_spans<'a>(directory: &'a str, label: Option<&'a str>) -> Vec<Span<'a>> {
     let mut title_items: Vec<Span> = vec![
         Span::styled("", Style::default()),
         Span::styled(" Arfima ", Style::default().reversed()),
         Span::styled("╱", Style::default().reversed().bg(Color::Reset)),
     ];
 
+    if let Some(label) = label {
+        title_items.push(Span::styled(
+            label,
+            Style::default().fg(Color::Black).bg(Color::Yellow),
+        ));
+        title_items.push(Span::styled(
+            "╱",
+            Style::default().reversed().bg(Color::Reset),
+        ));
+    }
+
     for directory in directory[1..].split("/") {
         title_items.push(Span::styled(
             format!(" {directory} "),
@@ -49,5 +63,67 @@ pub fn add_title_to_block<'a>(directory: &'a str, block: Block<'a>) -> Block<'a>
     title_items.pop();
     title_items.push(Span::styled("", Style::default()));
 
-    block.title(Line::from(title_items).bold())
+    title_items
+}
+
+pub fn add_title_to_block<'a>(
+    directory: &'a str,
+    label: Option<&'a str>,
+    block: Block<'a>,
+) -> Block<'a> {
+    block.title(Line::from(title_spans(directory, label)).bold())
+}
+
+/// The directory whose segment of the title is drawn `column` cells after the start of the title,
+/// for clicking the path
+pub fn title_directory_at(directory: &str, label: Option<&str>, column: u16) -> Option<PathBuf> {
+    // the app name and the label come before the directories, each followed by a separator
+    let first_directory = if label.is_some() { 5 } else { 3 };
+    let column = usize::from(column);
+    let mut start = 0;
+
+    for (i, span) in title_spans(directory, label).iter().enumerate() {
+        let end = start + span.width();
+
+        if (start..end).contains(&column) {
+            if i < first_directory || (i - first_directory) % 2 != 0 {
+                return None;
+            }
+
+            let depth = (i - first_directory) / 2;
+
+            return Some(Path::new(directory).components().take(depth + 2).collect());
+        }
+
+        start = end;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_directory_at() {
+        let spans = title_spans("/home/user", None);
+        let start = spans[..3].iter().map(Span::width).sum::<usize>() as u16;
+
+        assert_eq!(title_directory_at("/home/user", None, 0), None);
+        assert_eq!(
+            title_directory_at("/home/user", None, start),
+            Some(PathBuf::from("/home"))
+        );
+        // " home " and the separator after it
+        assert_eq!(title_directory_at("/home/user", None, start + 6), None);
+        assert_eq!(
+            title_directory_at("/home/user", Some(" PICK FILE "), start + 20),
+            Some(PathBuf::from("/home/user"))
+        );
+        assert_eq!(
+            title_directory_at("/", None, start),
+            Some(PathBuf::from("/"))
+        );
+    }
 }
@@ -47,31 +47,28 @@ impl InputState {
     fn remove_selection(&mut self) {
         if let InputStateMode::Select { origin } = self.mode {
             if self.cursor_position < origin {
-                if let Some((start, _)) = self.buffer.char_indices().nth(self.cursor_position) {
-                    if let Some((ch_end, ch)) = self
+                if let Some((start, _)) = self.buffer.char_indices().nth(self.cursor_position)
+                    && let Some((ch_end, ch)) = self
                         .buffer
                         .char_indices()
                         .nth(origin.min(self.buffer.chars().count().saturating_sub(1)))
-                    {
-                        let end = ch_end + ch.len_utf8();
-                        self.buffer.replace_range(start..end, "");
-                        self.cursor_position =
-                            self.cursor_position.min(self.buffer.chars().count());
-                    }
+                {
+                    let end = ch_end + ch.len_utf8();
+                    self.buffer.replace_range(start..end, "");
+                    self.cursor_position = self.cursor_position.min(self.buffer.chars().count());
                 }
             } else if let Some((start, _)) = self
                 .buffer
                 .char_indices()
                 .nth(origin.min(self.cursor_position))
-            {
-                if let Some((ch_end, ch)) = self.buffer.char_indices().nth(
+                && let Some((ch_end, ch)) = self.buffer.char_indices().nth(
                     self.cursor_position
                         .min(self.buffer.chars().count().saturating_sub(1)),
-                ) {
-                    let end = ch_end + ch.len_utf8();
-                    self.buffer.replace_range(start..end, "");
-                    self.cursor_position = origin.min(self.buffer.chars().count());
-                }
+                )
+            {
+                let end = ch_end + ch.len_utf8();
+                self.buffer.replace_range(start..end, "");
+                self.cursor_position = origin.min(self.buffer.chars().count());
             }
 
             self.mode = InputStateMode::Normal;
@@ -1,22 +1,26 @@
 use std::{
     any::{Any, TypeId},
-    path::PathBuf,
+    collections::HashMap,
+    path::{Path, PathBuf},
     sync::atomic::{AtomicU32, Ordering},
 };
 
 use crossbeam::channel::Sender;
-use crossterm::event::Event;
+use crossterm::event::{Event, MouseEvent};
 use ratatui::{
     Frame,
     layout::{Direction, Rect},
 };
 
-use crate::config::Config;
+use serde::{Deserialize, Serialize};
 
-use super::{App, AppEvent, Error, InputMode, Result};
+use crate::{config::Config, directory_entry::DiffStatus, keymap::Action};
 
-#[derive(Clone)]
+use super::{App, AppEvent, Error, InputMode, Result, WindowLayout};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub enum WindowSize {
+    #[default]
     Default,
     /// DefaultSize is for windows that start with a size in mind
     /// Example in neovim would be the explorer window that usually takes way less space than half
@@ -31,6 +35,30 @@ pub enum WindowSize {
     AdjustedSize(usize, isize),
 }
 
+impl WindowSize {
+    /// The size grown by `adjustment`, shrunk for a negative one
+    pub fn adjusted(&self, adjustment: isize) -> Self {
+        match *self {
+            Self::Default => Self::Adjusted(adjustment),
+            Self::DefaultSize(size) => Self::AdjustedSize(size, adjustment),
+            Self::Adjusted(prev) => Self::Adjusted(prev.saturating_add(adjustment)),
+            Self::AdjustedSize(size, prev) => {
+                Self::AdjustedSize(size, prev.saturating_add(adjustment))
+            }
+        }
+    }
+}
+
+/// A snapshot of the state of the focused window, used by anything that needs to act on "where the
+/// user is" without knowing which kind of window is focused
+#[derive(Clone, Debug, Default)]
+pub struct WindowContext {
+    pub id: u32,
+    pub directory: Option<PathBuf>,
+    pub selected: Option<PathBuf>,
+    pub marked: Vec<PathBuf>,
+}
+
 pub trait Window {
     fn id(&self) -> u32;
 
@@ -45,6 +73,46 @@ pub trait Window {
         handled: bool,
     ) -> bool;
 
+    /// Handles a click or the wheel at the mouse position inside `area`, where the window was
+    /// drawn
+    fn handle_mouse(
+        &mut self,
+        _mouse: &MouseEvent,
+        _double_click: bool,
+        _area: Rect,
+        _event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        false
+    }
+
+    /// The border between two windows at the position, as the id of their split and the index of
+    /// the window before the border
+    fn border_at(&self, _column: u16, _row: u16, _area: Rect) -> Option<(u32, usize)> {
+        None
+    }
+
+    /// Moves the border after window `index` of split `split_id` to the position
+    fn drag_border(
+        &mut self,
+        _split_id: u32,
+        _index: usize,
+        _column: u16,
+        _row: u16,
+        _area: Rect,
+    ) -> bool {
+        false
+    }
+
+    /// Performs a keymap action on the focused window, returning whether it was handled
+    fn handle_action(
+        &mut self,
+        _action: Action,
+        _count: Option<usize>,
+        _event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        false
+    }
+
     fn split(self: Box<Self>, direction: Direction, count: usize) -> Box<dyn Window>;
 
     fn reset(&mut self, _config: &Config) -> Result<()> {
@@ -67,6 +135,41 @@ pub trait Window {
         None
     }
 
+    /// The context of the pane opposite the focused one in commander mode
+    fn other_pane(&self) -> Option<WindowContext> {
+        None
+    }
+
+    /// The contexts of every window inside, in order
+    fn window_contexts(&self) -> Vec<WindowContext> {
+        self.focused_context().into_iter().collect()
+    }
+
+    /// Shows how the entries of the window `id` compare in `:diff`, `None` stops showing it
+    fn set_diff(&mut self, _id: u32, _statuses: Option<HashMap<PathBuf, DiffStatus>>) -> bool {
+        false
+    }
+
+    /// Focuses the opposite pane in commander mode, returning false outside of it
+    fn switch_pane(&mut self) -> bool {
+        false
+    }
+
+    /// Id of the window without other windows inside that has the focus
+    fn focused_window_id(&self) -> u32 {
+        self.id()
+    }
+
+    /// Moves the focus to the window `id` inside, returning false when it is not there
+    fn focus_window(&mut self, id: u32) -> bool {
+        self.id() == id
+    }
+
+    /// Where the windows without other windows inside are drawn when this window gets `area`
+    fn window_areas(&self, area: Rect) -> Vec<(u32, Rect)> {
+        vec![(self.id(), area)]
+    }
+
     fn get_window_size(&self) -> &WindowSize {
         &WindowSize::Default
     }
@@ -82,6 +185,45 @@ pub trait Window {
         false
     }
 
+    fn set_window_size(&mut self, _window_size: WindowSize) {}
+
+    /// The size the window starts with, what `<C-w>=` brings it back to
+    fn default_window_size(&self) -> WindowSize {
+        WindowSize::Default
+    }
+
+    /// Brings every window inside back to its default size
+    fn equalize_windows(&mut self) {}
+
+    /// Makes the focused window as large as possible in `direction`, shrinking the others
+    fn maximize_window(&mut self, _direction: Direction) {}
+
+    /// Exchanges the focused window with the next one in its split, or the previous one for the
+    /// last, returning false when there is no split to do it in
+    fn swap_window(&mut self) -> bool {
+        false
+    }
+
+    /// Moves every window of the split holding the focused window one place further, returning
+    /// false when there is no split to do it in
+    fn rotate_windows(&mut self) -> bool {
+        false
+    }
+
+    /// Takes the focused window out, returning it with what is left of this window
+    fn take_focused_window(self: Box<Self>) -> (Box<dyn Window>, Option<Box<dyn Window>>);
+
+    /// Adds `window` at the start or end of this split when it is laid out in `direction`, handing
+    /// `window` back otherwise
+    fn insert_at_edge(
+        &mut self,
+        window: Box<dyn Window>,
+        _direction: Direction,
+        _first: bool,
+    ) -> Option<Box<dyn Window>> {
+        Some(window)
+    }
+
     /// returns whether there is a window with the `id` inside somewhere
     fn includes(&self, id: u32) -> bool;
 
@@ -91,6 +233,26 @@ pub trait Window {
 
     fn open(self: Box<Self>, path: PathBuf, new: bool) -> (Box<dyn Window>, Option<Error>);
 
+    /// Changes the directory shown by the focused window, returning false when it cannot show one
+    fn change_directory(&mut self, _directory: &Path) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Selects the entry at `path` in the focused window, returning false when it is not listed
+    fn select(&mut self, _path: &Path) -> bool {
+        false
+    }
+
+    /// What a session saves of this window, `None` for windows that are not restored
+    fn layout(&self) -> Option<WindowLayout> {
+        None
+    }
+
+    /// Returns the context of the focused window inside this window
+    fn focused_context(&self) -> Option<WindowContext> {
+        None
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     fn includes_type_id(&self, type_id: TypeId) -> Option<u32>;
@@ -1,22 +1,44 @@
-mod key;
-
 use crossbeam::channel::Sender;
-use crossterm::event::Event;
-use key::handle_key_event;
-
-use crate::app::{AppEvent, InputMode};
 
-use super::CommonEntriesWindow;
+use crate::{
+    app::{AppEvent, windows::CommonEntriesWindow},
+    keymap::Action,
+};
 
-pub fn handle_event(
+pub fn handle_action(
     window: &mut CommonEntriesWindow,
-    input_mode: &InputMode,
-    event: &Event,
+    action: Action,
+    count: Option<usize>,
     event_tx: &Sender<AppEvent>,
 ) -> bool {
-    if let Event::Key(key) = event {
-        return handle_key_event(window, input_mode, key, event_tx);
+    match action {
+        Action::MoveDown => {
+            window.selected_index = window
+                .selected_index
+                .saturating_add(count.unwrap_or(1))
+                .min(window.entries.len().saturating_sub(1));
+        }
+        Action::MoveUp => {
+            window.selected_index = window.selected_index.saturating_sub(count.unwrap_or(1));
+        }
+        Action::Open | Action::OpenWith => {
+            if let Some(entity) = window.entries.get(window.selected_index) {
+                let _ = event_tx.send(AppEvent::Open {
+                    path: entity.path().to_owned(),
+                    new: action == Action::OpenWith,
+                    entry_type: entity.entry_type().to_owned(),
+                });
+            }
+        }
+        Action::JumpToTop => {
+            window.selected_index = count
+                .unwrap_or(1)
+                .saturating_sub(1)
+                .min(window.entries.len().saturating_sub(1));
+        }
+        Action::JumpToBottom => window.selected_index = window.entries.len().saturating_sub(1),
+        _ => return false,
     }
 
-    false
+    true
 }
@@ -15,6 +15,9 @@ use crate::{
     directory_entry::DirectoryEntry,
 };
 
+/// Columns the window takes next to the others until it is resized
+const DEFAULT_WIDTH: usize = 40;
+
 static COMMON_ENTRIES_WINDOW_ID: LazyLock<u32> = LazyLock::new(generate_window_id);
 
 pub struct CommonEntriesWindow {
@@ -67,7 +70,22 @@ impl CommonEntriesWindow {
         Self {
             entries: Self::entries(config),
             selected_index: 0,
-            window_size: WindowSize::DefaultSize(40),
+            window_size: WindowSize::DefaultSize(DEFAULT_WIDTH),
+            is_open: false,
+        }
+    }
+
+    pub fn with_selected_index_and_window_size(
+        config: &Config,
+        selected_index: usize,
+        window_size: WindowSize,
+    ) -> Self {
+        let entries = Self::entries(config);
+
+        Self {
+            selected_index: selected_index.min(entries.len().saturating_sub(1)),
+            entries,
+            window_size,
             is_open: false,
         }
     }
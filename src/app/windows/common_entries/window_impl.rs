@@ -15,15 +15,16 @@ use ratatui::{
 
 use crate::{
     app::{
-        App, AppEvent, Error, InputMode, Result,
+        App, AppEvent, Error, InputMode, Result, WindowLayout,
         widgets::draw_minimal_entries_table,
         window::{Window, WindowSize},
         windows::{FileManagerWindow, SplitWindow},
     },
     config::Config,
+    keymap::Action,
 };
 
-use super::{COMMON_ENTRIES_WINDOW_ID, CommonEntriesWindow, input::handle_event};
+use super::{COMMON_ENTRIES_WINDOW_ID, CommonEntriesWindow, DEFAULT_WIDTH, input::handle_action};
 
 impl Window for CommonEntriesWindow {
     fn id(&self) -> u32 {
@@ -49,17 +50,22 @@ impl Window for CommonEntriesWindow {
 
     fn handle_event(
         &mut self,
-        input_mode: &InputMode,
-        event: &Event,
-        focused: bool,
-        event_tx: &Sender<AppEvent>,
-        handled: bool,
+        _input_mode: &InputMode,
+        _event: &Event,
+        _focused: bool,
+        _event_tx: &Sender<AppEvent>,
+        _handled: bool,
     ) -> bool {
-        if !focused || handled {
-            return false;
-        }
+        false
+    }
 
-        handle_event(self, input_mode, event, event_tx)
+    fn handle_action(
+        &mut self,
+        action: Action,
+        count: Option<usize>,
+        event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        handle_action(self, action, count, event_tx)
     }
 
     fn reset(&mut self, config: &Config) -> Result<()> {
@@ -73,37 +79,48 @@ impl Window for CommonEntriesWindow {
         self
     }
 
+    fn take_focused_window(self: Box<Self>) -> (Box<dyn Window>, Option<Box<dyn Window>>) {
+        (self, None)
+    }
+
     fn get_window_size(&self) -> &WindowSize {
         &self.window_size
     }
 
+    fn set_window_size(&mut self, window_size: WindowSize) {
+        self.window_size = window_size;
+    }
+
+    fn default_window_size(&self) -> WindowSize {
+        WindowSize::DefaultSize(DEFAULT_WIDTH)
+    }
+
     fn adjust_window_size(
         &mut self,
         direction: Direction,
         adjustment: isize,
         parent: Option<(&Direction, usize)>,
     ) -> bool {
-        if let Some((d, windows)) = parent {
-            if d == &direction {
-                self.window_size = match self.window_size {
-                    WindowSize::Default => {
-                        WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
-                    }
-                    WindowSize::DefaultSize(size) => WindowSize::AdjustedSize(
-                        size,
-                        adjustment.saturating_mul(windows.cast_signed()),
-                    ),
-                    WindowSize::Adjusted(prev) => WindowSize::Adjusted(
-                        prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                    ),
-                    WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
-                        size,
-                        prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                    ),
-                };
-
-                return true;
-            }
+        if let Some((d, windows)) = parent
+            && d == &direction
+        {
+            self.window_size = match self.window_size {
+                WindowSize::Default => {
+                    WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
+                }
+                WindowSize::DefaultSize(size) => {
+                    WindowSize::AdjustedSize(size, adjustment.saturating_mul(windows.cast_signed()))
+                }
+                WindowSize::Adjusted(prev) => WindowSize::Adjusted(
+                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+                ),
+                WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
+                    size,
+                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+                ),
+            };
+
+            return true;
         }
 
         false
@@ -129,6 +146,13 @@ impl Window for CommonEntriesWindow {
         )
     }
 
+    fn layout(&self) -> Option<WindowLayout> {
+        Some(WindowLayout::CommonEntries {
+            selected_index: self.selected_index,
+            window_size: self.window_size.clone(),
+        })
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -31,6 +31,10 @@ impl Window for DummyWindow {
         self
     }
 
+    fn take_focused_window(self: Box<Self>) -> (Box<dyn Window>, Option<Box<dyn Window>>) {
+        (self, None)
+    }
+
     fn includes(&self, id: u32) -> bool {
         id == 0
     }
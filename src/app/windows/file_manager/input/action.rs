@@ -0,0 +1,155 @@
+use std::path::{Path, PathBuf};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    app::{AppEvent, InputMode, widgets::types::InputState, windows::FileManagerWindow},
+    directory_entry::{self, DirectoryEntryType, vfs},
+    keymap::Action,
+};
+
+/// The path to hand to other programs, entries of archives are extracted to a temporary
+/// directory first
+fn real_path(path: &Path) -> directory_entry::Result<PathBuf> {
+    if vfs::is_virtual(path) {
+        vfs::extract_to_temp(path)
+    } else {
+        Ok(path.to_owned())
+    }
+}
+
+fn send_open(path: &Path, new: bool, entry_type: &DirectoryEntryType, event_tx: &Sender<AppEvent>) {
+    let event = match real_path(path) {
+        Ok(path) => AppEvent::Open {
+            path,
+            new,
+            entry_type: entry_type.to_owned(),
+        },
+        Err(e) => AppEvent::SetError(Some(e.into())),
+    };
+
+    let _ = event_tx.send(event);
+}
+
+pub fn handle_action(
+    window: &mut FileManagerWindow,
+    action: Action,
+    count: Option<usize>,
+    event_tx: &Sender<AppEvent>,
+) -> bool {
+    match action {
+        Action::MoveDown => {
+            window.selected_index = window
+                .selected_index
+                .saturating_add(count.unwrap_or(1))
+                .min(window.entries.len().saturating_sub(1));
+        }
+        Action::MoveUp => {
+            window.selected_index = window.selected_index.saturating_sub(count.unwrap_or(1));
+        }
+        Action::GoParent => {
+            if count == Some(0) {
+                return true;
+            }
+
+            let mut count = count.unwrap_or(1).saturating_sub(1);
+
+            let mut target_directory: &Path;
+
+            if let Some(parent) = Path::new(&window.directory).parent() {
+                target_directory = parent;
+            } else {
+                return true;
+            }
+
+            while let Some(parent) = Path::new(target_directory).parent() {
+                if count == 0 {
+                    break;
+                }
+
+                target_directory = parent;
+                count = count.saturating_sub(1);
+            }
+
+            if let Err(e) = window.set_directory(target_directory.to_string_lossy().to_string()) {
+                let _ = event_tx.send(AppEvent::SetError(Some(e)));
+            }
+        }
+        Action::Open => {
+            if let Some(entry) = window.entries.get(window.selected_index) {
+                match entry.entry_type() {
+                    DirectoryEntryType::Directory => {
+                        let _ = window.set_directory(entry.path().to_string_lossy().to_string());
+                    }
+                    _ if vfs::is_archive(entry.path()) => {
+                        if let Err(e) =
+                            window.set_directory(entry.path().to_string_lossy().to_string())
+                        {
+                            let _ = event_tx.send(AppEvent::SetError(Some(e)));
+                        }
+                    }
+                    entry_type => send_open(entry.path(), false, entry_type, event_tx),
+                }
+            }
+        }
+        Action::Edit => {
+            if let Some(entry) = window.entries.get(window.selected_index)
+                && *entry.entry_type() != DirectoryEntryType::Directory
+            {
+                match real_path(entry.path()) {
+                    Ok(path) => {
+                        let _ = event_tx.send(AppEvent::Edit(path));
+                    }
+                    Err(e) => {
+                        let _ = event_tx.send(AppEvent::SetError(Some(e.into())));
+                    }
+                }
+            }
+        }
+        Action::OpenWith => {
+            if let Some(entry) = window.entries.get(window.selected_index)
+                && *entry.entry_type() != DirectoryEntryType::Directory
+            {
+                send_open(entry.path(), true, entry.entry_type(), event_tx);
+            }
+        }
+        Action::Add | Action::Rename | Action::Remove
+            if vfs::is_virtual_directory(Path::new(&window.directory)) =>
+        {
+            let error = directory_entry::Error::ReadOnly(PathBuf::from(&window.directory));
+            let _ = event_tx.send(AppEvent::SetError(Some(error.into())));
+        }
+        Action::Add => {
+            let _ = event_tx.send(AppEvent::UpdateInputMode(InputMode::Adding {
+                state: InputState::new(""),
+            }));
+        }
+        Action::Rename => {
+            if let Some(entry) = window.entries.get(window.selected_index) {
+                let _ = event_tx.send(AppEvent::UpdateInputMode(InputMode::Renaming {
+                    original: entry.name().into(),
+                    state: InputState::new(entry.name()),
+                }));
+            }
+        }
+        Action::Mark => window.toggle_mark(),
+        Action::Remove => {
+            if let Some(entry) = window.entries.get(window.selected_index) {
+                let _ = event_tx.send(AppEvent::UpdateInputMode(InputMode::Removing {
+                    path: entry.path().to_string_lossy().to_string(),
+                    removing_selected: false,
+                }));
+            }
+        }
+        Action::JumpToTop => {
+            window.selected_index = count
+                .unwrap_or(1)
+                .saturating_sub(1)
+                .min(window.entries.len().saturating_sub(1));
+        }
+        Action::JumpToBottom => window.selected_index = window.entries.len().saturating_sub(1),
+        _ => return false,
+    }
+
+    true
+}
@@ -1,5 +1,4 @@
 mod adding;
-mod normal;
 mod removing;
 mod renaming;
 
@@ -20,9 +19,6 @@ pub fn handle_key_event(
         return false;
     }
 
-    if normal::handle(window, input_mode, key, event_tx) {
-        return true;
-    }
     if adding::handle(window, input_mode, key, event_tx) {
         return true;
     }
@@ -1,4 +1,9 @@
+mod action;
 mod key;
+mod mouse;
+
+pub use action::handle_action;
+pub use mouse::handle_mouse;
 
 use crossbeam::channel::Sender;
 use crossterm::event::Event;
@@ -0,0 +1,61 @@
+use crossbeam::channel::Sender;
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+
+use crate::{
+    app::{AppEvent, windows::FileManagerWindow},
+    keymap::Action,
+};
+
+use super::handle_action;
+
+/// Entries the selection moves for every step of the wheel
+const SCROLL_LINES: usize = 3;
+
+/// The index of the entry drawn on `row`, following how the table scrolls to keep the selection
+/// at its bottom
+fn entry_index_at(window: &FileManagerWindow, row: u16, area: Rect) -> Option<usize> {
+    // the border and the header come before the first entry
+    let first_row = area.y + 2;
+    let visible_rows = usize::from(area.height.saturating_sub(3));
+
+    if row < first_row || row >= area.bottom().saturating_sub(1) {
+        return None;
+    }
+
+    let offset = (window.selected_index + 1).saturating_sub(visible_rows);
+    let index = offset + usize::from(row - first_row);
+
+    (index < window.entries.len()).then_some(index)
+}
+
+pub fn handle_mouse(
+    window: &mut FileManagerWindow,
+    mouse: &MouseEvent,
+    double_click: bool,
+    area: Rect,
+    event_tx: &Sender<AppEvent>,
+) -> bool {
+    match mouse.kind {
+        MouseEventKind::ScrollDown => {
+            handle_action(window, Action::MoveDown, Some(SCROLL_LINES), event_tx)
+        }
+        MouseEventKind::ScrollUp => {
+            handle_action(window, Action::MoveUp, Some(SCROLL_LINES), event_tx)
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            let Some(index) = entry_index_at(window, mouse.row, area) else {
+                return false;
+            };
+
+            window.selected_index = index;
+
+            if double_click {
+                handle_action(window, Action::Open, None, event_tx);
+            }
+
+            true
+        }
+        _ => false,
+    }
+}
@@ -1,14 +1,17 @@
 mod input;
 mod window_impl;
 
-use std::path::Path;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 use crate::{
     app::{
         Error, Result,
         window::{WindowSize, generate_window_id},
     },
-    directory_entry::{DirectoryEntry, read_directory},
+    directory_entry::{DiffStatus, DirectoryEntry, read_directory, vfs},
 };
 
 use super::SplitWindow;
@@ -19,14 +22,17 @@ pub struct FileManagerWindow {
     directory: String,
     entries: Vec<DirectoryEntry>,
     selected_index: usize,
+    marked: HashSet<PathBuf>,
     window_size: WindowSize,
+    /// How the entries compare to the other window of `:diff`
+    diff: Option<HashMap<PathBuf, DiffStatus>>,
 }
 
 impl FileManagerWindow {
     pub fn new(directory: &str) -> Result<Self> {
         let path = Path::new(directory);
 
-        if !path.is_dir() {
+        if !vfs::is_directory(path) {
             return Err(Error::InvalidDirectoryPath(directory.into()));
         }
 
@@ -35,6 +41,8 @@ impl FileManagerWindow {
             directory: directory.into(),
             entries: read_directory(path)?,
             selected_index: 0,
+            marked: HashSet::new(),
+            diff: None,
             window_size: WindowSize::Default,
         })
     }
@@ -46,7 +54,7 @@ impl FileManagerWindow {
     ) -> Result<Self> {
         let path = Path::new(directory);
 
-        if !path.is_dir() {
+        if !vfs::is_directory(path) {
             return Err(Error::InvalidDirectoryPath(directory.into()));
         }
 
@@ -55,6 +63,8 @@ impl FileManagerWindow {
             directory: directory.into(),
             entries: read_directory(path)?,
             selected_index: 0,
+            marked: HashSet::new(),
+            diff: None,
             window_size,
         })
     }
@@ -62,14 +72,51 @@ impl FileManagerWindow {
     pub fn set_directory(&mut self, directory: String) -> Result<()> {
         let path = Path::new(&directory);
 
-        if !path.is_dir() {
+        if !vfs::is_directory(path) {
             return Err(Error::InvalidDirectoryPath(directory));
         }
 
         self.entries = read_directory(path)?;
         self.directory = directory;
         self.selected_index = 0;
+        self.marked.clear();
+        self.diff = None;
 
         Ok(())
     }
+
+    /// Toggles the mark of the selected entry and moves the selection down
+    pub fn toggle_mark(&mut self) {
+        if let Some(entry) = self.entries.get(self.selected_index) {
+            if !self.marked.remove(entry.path()) {
+                self.marked.insert(entry.path().to_owned());
+            }
+
+            self.selected_index = self
+                .selected_index
+                .saturating_add(1)
+                .min(self.entries.len().saturating_sub(1));
+        }
+    }
+
+    /// Selects the entry at `path`, keeping the selection and returning false when it is not
+    /// listed
+    pub fn select_path(&mut self, path: &Path) -> bool {
+        match self.entries.iter().position(|entry| entry.path() == path) {
+            Some(index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marked paths in the order they are listed
+    pub fn marked_paths(&self) -> Vec<PathBuf> {
+        self.entries
+            .iter()
+            .filter(|entry| self.marked.contains(entry.path()))
+            .map(|entry| entry.path().to_owned())
+            .collect()
+    }
 }
@@ -1,10 +1,11 @@
 use std::{
     any::{Any, TypeId},
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
 
 use crossbeam::channel::Sender;
-use crossterm::event::Event;
+use crossterm::event::{Event, MouseEvent};
 use ratatui::{
     Frame,
     layout::{Direction, Rect},
@@ -14,15 +15,19 @@ use ratatui::{
 
 use crate::{
     app::{
-        App, AppEvent, Error, InputMode, Result,
-        widgets::{add_title_to_block, draw_entries_table},
-        window::{Window, WindowSize, generate_window_id},
+        App, AppEvent, Error, InputMode, Picker, Result, WindowLayout,
+        widgets::{EntriesHighlight, add_title_to_block, draw_entries_table},
+        window::{Window, WindowContext, WindowSize, generate_window_id},
     },
     config::Config,
-    directory_entry::read_directory,
+    directory_entry::{DiffStatus, read_directory, vfs},
+    keymap::Action,
 };
 
-use super::{FileManagerWindow, SplitWindow, input::handle_event};
+use super::{
+    FileManagerWindow, SplitWindow,
+    input::{handle_action, handle_event, handle_mouse},
+};
 
 impl Window for FileManagerWindow {
     fn id(&self) -> u32 {
@@ -36,13 +41,17 @@ impl Window for FileManagerWindow {
             block = block.border_style(Style::default().fg(Color::Cyan));
         }
 
-        block = add_title_to_block(&self.directory, block);
+        block = add_title_to_block(&self.directory, app.picker().map(Picker::label), block);
 
         draw_entries_table(
             frame,
             area,
             &self.entries,
-            self.selected_index,
+            EntriesHighlight {
+                selected_index: self.selected_index,
+                marked: &self.marked,
+                diff: self.diff.as_ref(),
+            },
             block,
             &app.config,
         );
@@ -63,9 +72,34 @@ impl Window for FileManagerWindow {
         handle_event(self, input_mode, event, event_tx)
     }
 
+    fn handle_mouse(
+        &mut self,
+        mouse: &MouseEvent,
+        double_click: bool,
+        area: Rect,
+        event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        handle_mouse(self, mouse, double_click, area, event_tx)
+    }
+
+    fn handle_action(
+        &mut self,
+        action: Action,
+        count: Option<usize>,
+        event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        handle_action(self, action, count, event_tx)
+    }
+
     fn reset(&mut self, _: &Config) -> Result<()> {
         self.entries = read_directory(Path::new(&self.directory))?;
-        self.selected_index = self.selected_index.min(self.entries.len() - 1);
+        self.selected_index = self
+            .selected_index
+            .min(self.entries.len().saturating_sub(1));
+
+        let entries = &self.entries;
+        self.marked
+            .retain(|path| entries.iter().any(|entry| entry.path() == path));
 
         Ok(())
     }
@@ -79,6 +113,8 @@ impl Window for FileManagerWindow {
                 directory: self.directory.clone(),
                 entries: self.entries.clone(),
                 selected_index: self.selected_index,
+                marked: self.marked.clone(),
+                diff: None,
                 window_size: WindowSize::Default,
             }));
         }
@@ -90,37 +126,44 @@ impl Window for FileManagerWindow {
         ))
     }
 
+    fn take_focused_window(self: Box<Self>) -> (Box<dyn Window>, Option<Box<dyn Window>>) {
+        (self, None)
+    }
+
     fn get_window_size(&self) -> &WindowSize {
         &self.window_size
     }
 
+    fn set_window_size(&mut self, window_size: WindowSize) {
+        self.window_size = window_size;
+    }
+
     fn adjust_window_size(
         &mut self,
         direction: Direction,
         adjustment: isize,
         parent: Option<(&Direction, usize)>,
     ) -> bool {
-        if let Some((d, windows)) = parent {
-            if d == &direction {
-                self.window_size = match self.window_size {
-                    WindowSize::Default => {
-                        WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
-                    }
-                    WindowSize::DefaultSize(size) => WindowSize::AdjustedSize(
-                        size,
-                        adjustment.saturating_mul(windows.cast_signed()),
-                    ),
-                    WindowSize::Adjusted(prev) => WindowSize::Adjusted(
-                        prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                    ),
-                    WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
-                        size,
-                        prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                    ),
-                };
-
-                return true;
-            }
+        if let Some((d, windows)) = parent
+            && d == &direction
+        {
+            self.window_size = match self.window_size {
+                WindowSize::Default => {
+                    WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
+                }
+                WindowSize::DefaultSize(size) => {
+                    WindowSize::AdjustedSize(size, adjustment.saturating_mul(windows.cast_signed()))
+                }
+                WindowSize::Adjusted(prev) => WindowSize::Adjusted(
+                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+                ),
+                WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
+                    size,
+                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+                ),
+            };
+
+            return true;
         }
 
         false
@@ -133,7 +176,7 @@ impl Window for FileManagerWindow {
     fn open(self: Box<Self>, path: PathBuf, _: bool) -> (Box<dyn Window>, Option<Error>) {
         let path = Path::new(&path);
 
-        if !path.is_dir() {
+        if !vfs::is_directory(path) {
             return (
                 self,
                 Some(Error::InvalidDirectoryPath(
@@ -153,12 +196,56 @@ impl Window for FileManagerWindow {
                 directory: path.to_string_lossy().to_string(),
                 entries,
                 selected_index: 0,
+                marked: HashSet::new(),
+                diff: None,
                 window_size: self.window_size,
             }),
             None,
         )
     }
 
+    fn change_directory(&mut self, directory: &Path) -> Result<bool> {
+        self.set_directory(directory.to_string_lossy().to_string())?;
+
+        Ok(true)
+    }
+
+    fn select(&mut self, path: &Path) -> bool {
+        self.select_path(path)
+    }
+
+    fn set_diff(&mut self, id: u32, statuses: Option<HashMap<PathBuf, DiffStatus>>) -> bool {
+        if self.id != id {
+            return false;
+        }
+
+        self.diff = statuses;
+        true
+    }
+
+    fn layout(&self) -> Option<WindowLayout> {
+        Some(WindowLayout::FileManager {
+            directory: PathBuf::from(&self.directory),
+            selected: self
+                .entries
+                .get(self.selected_index)
+                .map(|entry| entry.path().to_owned()),
+            window_size: self.window_size.clone(),
+        })
+    }
+
+    fn focused_context(&self) -> Option<WindowContext> {
+        Some(WindowContext {
+            id: self.id,
+            directory: Some(PathBuf::from(&self.directory)),
+            selected: self
+                .entries
+                .get(self.selected_index)
+                .map(|entry| entry.path().to_owned()),
+            marked: self.marked_paths(),
+        })
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -0,0 +1,28 @@
+use crate::app::window::WindowContext;
+
+use super::SplitWindow;
+
+/// The pane that is not focused, when this is the commander split
+fn other_pane_index(split: &SplitWindow) -> Option<usize> {
+    (split.commander && split.windows.len() == 2).then(|| 1 - split.focused_index.min(1))
+}
+
+pub fn other_pane(split: &SplitWindow) -> Option<WindowContext> {
+    match other_pane_index(split) {
+        Some(index) => split.windows[index].focused_context(),
+        None => split.windows.get(split.focused_index)?.other_pane(),
+    }
+}
+
+pub fn switch_pane(split: &mut SplitWindow) -> bool {
+    match other_pane_index(split) {
+        Some(index) => {
+            split.focused_index = index;
+            true
+        }
+        None => split
+            .windows
+            .get_mut(split.focused_index)
+            .is_some_and(|window| window.switch_pane()),
+    }
+}
@@ -74,12 +74,12 @@ pub fn remove(mut split: SplitWindow, id: u32) -> Option<Box<dyn Window>> {
         } else if split.focused_index >= split.windows.len() {
             split.focused_index = split.windows.len() - 1;
         }
-    } else if let Some(i) = includes_index {
-        if let Some(window) = split.windows.get_mut(i) {
-            let old_window = std::mem::replace(window, Box::new(DummyWindow));
-            if let Some(new_child) = old_window.remove(id) {
-                split.windows[i] = new_child;
-            }
+    } else if let Some(i) = includes_index
+        && let Some(window) = split.windows.get_mut(i)
+    {
+        let old_window = std::mem::replace(window, Box::new(DummyWindow));
+        if let Some(new_child) = old_window.remove(id) {
+            split.windows[i] = new_child;
         }
     }
 
@@ -122,18 +122,18 @@ pub fn open(
                 };
                 split.focused_index = i;
             }
-        } else if let Some(i) = includes_index {
-            if let Some(window) = split.windows.get_mut(i) {
-                let window = std::mem::replace(window, Box::new(DummyWindow));
-                let (window, error) = window.open(path, false);
-                split.windows[i] = window;
+        } else if let Some(i) = includes_index
+            && let Some(window) = split.windows.get_mut(i)
+        {
+            let window = std::mem::replace(window, Box::new(DummyWindow));
+            let (window, error) = window.open(path, false);
+            split.windows[i] = window;
 
-                if let Some(e) = error {
-                    return (Box::new(split), Some(e));
-                }
-
-                split.focused_index = i;
+            if let Some(e) = error {
+                return (Box::new(split), Some(e));
             }
+
+            split.focused_index = i;
         }
     } else {
         split.windows.push(
@@ -150,3 +150,137 @@ pub fn open(
 
     (Box::new(split), None)
 }
+
+pub fn swap_window(split: &mut SplitWindow) -> bool {
+    if let Some(window) = split.windows.get_mut(split.focused_index)
+        && window.swap_window()
+    {
+        return true;
+    }
+
+    if split.windows.len() < 2 {
+        return false;
+    }
+
+    let other = if split.focused_index + 1 < split.windows.len() {
+        split.focused_index + 1
+    } else {
+        split.focused_index - 1
+    };
+
+    split.windows.swap(split.focused_index, other);
+    split.focused_index = other;
+
+    true
+}
+
+pub fn rotate_windows(split: &mut SplitWindow) -> bool {
+    if let Some(window) = split.windows.get_mut(split.focused_index)
+        && window.rotate_windows()
+    {
+        return true;
+    }
+
+    if split.windows.len() < 2 {
+        return false;
+    }
+
+    split.windows.rotate_right(1);
+    split.focused_index = (split.focused_index + 1) % split.windows.len();
+
+    true
+}
+
+pub fn take_focused_window(mut split: SplitWindow) -> (Box<dyn Window>, Option<Box<dyn Window>>) {
+    if split.windows.is_empty() {
+        return (Box::new(DummyWindow), None);
+    }
+
+    let window = split.windows.remove(split.focused_index);
+    let (taken, rest) = window.take_focused_window();
+
+    if let Some(rest) = rest {
+        split.windows.insert(split.focused_index, rest);
+    } else if split.windows.len() == 1 {
+        return (taken, Some(split.windows.remove(0)));
+    } else if split.focused_index >= split.windows.len() {
+        split.focused_index = split.windows.len() - 1;
+    }
+
+    (taken, Some(Box::new(split)))
+}
+
+pub fn insert_at_edge(
+    split: &mut SplitWindow,
+    window: Box<dyn Window>,
+    direction: Direction,
+    first: bool,
+) -> Option<Box<dyn Window>> {
+    if split.direction != direction {
+        return Some(window);
+    }
+
+    if first {
+        split.windows.insert(0, window);
+        split.focused_index = 0;
+    } else {
+        split.windows.push(window);
+        split.focused_index = split.windows.len() - 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn windows(count: usize) -> (tempfile::TempDir, Vec<Box<dyn Window>>) {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().to_string_lossy().to_string();
+        let windows = (0..count)
+            .map(|_| Box::new(FileManagerWindow::new(&path).unwrap()) as Box<dyn Window>)
+            .collect();
+
+        (directory, windows)
+    }
+
+    fn ids(split: &SplitWindow) -> Vec<u32> {
+        split.windows.iter().map(|window| window.id()).collect()
+    }
+
+    #[test]
+    fn test_swap_and_rotate_keep_focus_on_the_window() {
+        let (_directory, windows) = windows(3);
+        let mut split = SplitWindow::new(Direction::Horizontal, windows);
+        let [a, b, c] = ids(&split)[..] else {
+            unreachable!()
+        };
+
+        assert!(swap_window(&mut split));
+        assert_eq!(ids(&split), [b, a, c]);
+        assert_eq!(split.focused_index, 1);
+
+        assert!(rotate_windows(&mut split));
+        assert_eq!(ids(&split), [c, b, a]);
+        assert_eq!(split.focused_index, 2);
+
+        assert!(swap_window(&mut split));
+        assert_eq!(ids(&split), [c, a, b]);
+        assert_eq!(split.focused_index, 1);
+    }
+
+    #[test]
+    fn test_take_focused_window_collapses_the_split() {
+        let (_directory, windows) = windows(2);
+        let split = SplitWindow::with_focused_index(Direction::Vertical, windows, 1);
+        let [a, b] = ids(&split)[..] else {
+            unreachable!()
+        };
+
+        let (taken, rest) = take_focused_window(split);
+
+        assert_eq!(taken.id(), b);
+        assert_eq!(rest.map(|window| window.id()), Some(a));
+    }
+}
@@ -1,4 +1,6 @@
+mod commander;
 mod management;
+mod mouse;
 mod navigation;
 mod render;
 mod sizing;
@@ -16,6 +18,8 @@ pub struct SplitWindow {
     windows: Vec<Box<dyn Window>>,
     focused_index: usize,
     window_size: WindowSize,
+    /// Two panes side by side with a function key bar, the other pane is where copies and moves go
+    commander: bool,
 }
 
 impl SplitWindow {
@@ -26,6 +30,7 @@ impl SplitWindow {
             windows,
             focused_index: 0,
             window_size: WindowSize::Default,
+            commander: false,
         }
     }
 
@@ -40,6 +45,7 @@ impl SplitWindow {
             windows,
             focused_index: 0,
             window_size,
+            commander: false,
         }
     }
 
@@ -54,6 +60,40 @@ impl SplitWindow {
             focused_index: focused_index.min(windows.len() - 1),
             windows,
             window_size: WindowSize::Default,
+            commander: false,
         }
     }
+
+    pub fn with_focused_index_and_window_size(
+        direction: Direction,
+        windows: Vec<Box<dyn Window>>,
+        focused_index: usize,
+        window_size: WindowSize,
+    ) -> Self {
+        Self {
+            id: generate_window_id(),
+            direction,
+            focused_index: focused_index.min(windows.len() - 1),
+            windows,
+            window_size,
+            commander: false,
+        }
+    }
+
+    /// The two panes of commander mode side by side, the first one focused
+    pub fn commander(left: Box<dyn Window>, right: Box<dyn Window>) -> Self {
+        Self {
+            commander: true,
+            ..Self::new(Direction::Horizontal, vec![left, right])
+        }
+    }
+
+    pub fn with_commander(mut self, commander: bool) -> Self {
+        self.commander = commander;
+        self
+    }
+
+    pub fn is_commander(&self) -> bool {
+        self.commander
+    }
 }
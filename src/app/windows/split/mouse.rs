@@ -0,0 +1,144 @@
+use crossbeam::channel::Sender;
+use crossterm::event::MouseEvent;
+use ratatui::layout::{Direction, Position, Rect};
+
+use crate::app::AppEvent;
+
+use super::{SplitWindow, render};
+
+/// Columns or rows a window keeps when a border is dragged over it
+const MINIMUM_WINDOW_SIZE: u16 = 3;
+
+pub fn handle_mouse(
+    split: &mut SplitWindow,
+    mouse: &MouseEvent,
+    double_click: bool,
+    area: Rect,
+    event_tx: &Sender<AppEvent>,
+) -> bool {
+    let position = Position::new(mouse.column, mouse.row);
+    let areas = render::areas(split, area);
+
+    split
+        .windows
+        .iter_mut()
+        .zip(areas)
+        .find(|(_, area)| area.contains(position))
+        .is_some_and(|(window, area)| window.handle_mouse(mouse, double_click, area, event_tx))
+}
+
+/// The last column or row of a window, where the border after it is drawn
+fn border_of(direction: Direction, area: Rect) -> u16 {
+    match direction {
+        Direction::Horizontal => area.right().saturating_sub(1),
+        Direction::Vertical => area.bottom().saturating_sub(1),
+    }
+}
+
+pub fn border_at(split: &SplitWindow, column: u16, row: u16, area: Rect) -> Option<(u32, usize)> {
+    let areas = render::areas(split, area);
+    let position = Position::new(column, row);
+
+    for (i, (window, window_area)) in split.windows.iter().zip(&areas).enumerate() {
+        if !window_area.contains(position) {
+            continue;
+        }
+
+        if let Some(border) = window.border_at(column, row, *window_area) {
+            return Some(border);
+        }
+
+        let coordinate = match split.direction {
+            Direction::Horizontal => column,
+            Direction::Vertical => row,
+        };
+
+        if i + 1 < areas.len() && coordinate == border_of(split.direction, *window_area) {
+            return Some((split.id, i));
+        }
+    }
+
+    None
+}
+
+pub fn drag_border(
+    split: &mut SplitWindow,
+    split_id: u32,
+    index: usize,
+    column: u16,
+    row: u16,
+    area: Rect,
+) -> bool {
+    let areas = render::areas(split, area);
+
+    if split.id != split_id {
+        return split
+            .windows
+            .iter_mut()
+            .zip(areas)
+            .any(|(window, area)| window.drag_border(split_id, index, column, row, area));
+    }
+
+    let (Some(before), Some(after)) = (areas.get(index), areas.get(index + 1)) else {
+        return false;
+    };
+
+    let (coordinate, before_size, after_size) = match split.direction {
+        Direction::Horizontal => (column, before.width, after.width),
+        Direction::Vertical => (row, before.height, after.height),
+    };
+
+    let delta = (coordinate as isize - border_of(split.direction, *before) as isize).clamp(
+        -(before_size.saturating_sub(MINIMUM_WINDOW_SIZE) as isize),
+        after_size.saturating_sub(MINIMUM_WINDOW_SIZE) as isize,
+    );
+
+    if delta == 0 {
+        return true;
+    }
+
+    // growing one window by what the other shrinks leaves the rest of the split as it is
+    for (i, adjustment) in [(index, delta), (index + 1, -delta)] {
+        let window = &mut split.windows[i];
+        window.set_window_size(window.get_window_size().adjusted(adjustment));
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::{
+        window::{Window, WindowSize},
+        windows::FileManagerWindow,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_drag_border_between_windows() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().to_string_lossy().to_string();
+        let windows: Vec<Box<dyn Window>> = vec![
+            Box::new(FileManagerWindow::new(&path).unwrap()),
+            Box::new(FileManagerWindow::new(&path).unwrap()),
+        ];
+        let mut split = SplitWindow::new(Direction::Horizontal, windows);
+        let area = Rect::new(0, 0, 80, 20);
+        let id = split.id;
+
+        assert_eq!(border_at(&split, 39, 5, area), Some((id, 0)));
+        assert_eq!(border_at(&split, 40, 5, area), None);
+
+        assert!(drag_border(&mut split, id, 0, 49, 5, area));
+        assert_eq!(render::areas(&split, area)[0].width, 50);
+        assert!(matches!(
+            split.windows[1].get_window_size(),
+            WindowSize::Adjusted(-10)
+        ));
+
+        // the window after the border keeps a few columns however far it is dragged
+        assert!(drag_border(&mut split, id, 0, 200, 5, area));
+        assert_eq!(render::areas(&split, area)[1].width, MINIMUM_WINDOW_SIZE);
+    }
+}
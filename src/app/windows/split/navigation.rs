@@ -56,3 +56,12 @@ pub fn abs_prev_window(split: &mut SplitWindow) {
         window.abs_prev_window();
     }
 }
+
+pub fn focus_window(split: &mut SplitWindow, id: u32) -> bool {
+    let Some(index) = split.windows.iter().position(|window| window.includes(id)) else {
+        return false;
+    };
+
+    split.focused_index = index;
+    split.windows[index].focus_window(id)
+}
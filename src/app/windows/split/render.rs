@@ -3,11 +3,38 @@ use ratatui::{
     layout::{Direction, Rect},
 };
 
-use crate::app::{App, window::WindowSize};
+use crate::app::{App, widgets::render_function_key_bar, window::WindowSize};
 
 use super::SplitWindow;
 
 pub fn render(split: &SplitWindow, app: &App, frame: &mut Frame, area: Rect, focused: bool) {
+    if split.commander {
+        let bar = Rect {
+            y: area.bottom().saturating_sub(1),
+            height: area.height.min(1),
+            ..area
+        };
+
+        render_function_key_bar(frame, bar, &app.keymap);
+    }
+
+    for (i, (window, area)) in split.windows.iter().zip(areas(split, area)).enumerate() {
+        window.render(app, frame, area, focused && split.focused_index == i);
+    }
+}
+
+/// Where each window of the split is drawn inside `area`
+pub fn areas(split: &SplitWindow, area: Rect) -> Vec<Rect> {
+    // commander mode keeps the last row for its function key bar
+    let area = if split.commander {
+        Rect {
+            height: area.height.saturating_sub(1),
+            ..area
+        }
+    } else {
+        area
+    };
+
     let mut areas = vec![area; split.windows.len()];
     let mut window_sizes = vec![0_usize; split.windows.len()];
     let mut constant_width = 0;
@@ -109,7 +136,5 @@ pub fn render(split: &SplitWindow, app: &App, frame: &mut Frame, area: Rect, foc
         }
     }
 
-    for (i, window) in split.windows.iter().enumerate() {
-        window.render(app, frame, areas[i], focused && split.focused_index == i);
-    }
+    areas
 }
@@ -12,33 +12,59 @@ pub fn adjust_window_size(
 ) -> bool {
     let windows_len = split.windows.len();
 
-    if let Some(window) = split.windows.get_mut(split.focused_index) {
-        if window.adjust_window_size(direction, adjustment, Some((&split.direction, windows_len))) {
-            return true;
-        }
+    if let Some(window) = split.windows.get_mut(split.focused_index)
+        && window.adjust_window_size(direction, adjustment, Some((&split.direction, windows_len)))
+    {
+        return true;
     }
 
-    if let Some((d, windows)) = parent {
-        if d == &direction {
-            split.window_size = match split.window_size {
-                WindowSize::Default => {
-                    WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
-                }
-                WindowSize::DefaultSize(size) => {
-                    WindowSize::AdjustedSize(size, adjustment.saturating_mul(windows.cast_signed()))
-                }
-                WindowSize::Adjusted(prev) => WindowSize::Adjusted(
-                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                ),
-                WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
-                    size,
-                    prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
-                ),
-            };
-
-            return true;
-        }
+    if let Some((d, windows)) = parent
+        && d == &direction
+    {
+        split.window_size = match split.window_size {
+            WindowSize::Default => {
+                WindowSize::Adjusted(adjustment.saturating_mul(windows.cast_signed()))
+            }
+            WindowSize::DefaultSize(size) => {
+                WindowSize::AdjustedSize(size, adjustment.saturating_mul(windows.cast_signed()))
+            }
+            WindowSize::Adjusted(prev) => WindowSize::Adjusted(
+                prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+            ),
+            WindowSize::AdjustedSize(size, prev) => WindowSize::AdjustedSize(
+                size,
+                prev.saturating_add(adjustment.saturating_mul(windows.cast_signed())),
+            ),
+        };
+
+        return true;
     }
 
     false
 }
+
+/// Rows or columns left to a window shrunk by maximizing another, enough for its borders
+const MINIMIZED_WINDOW_SIZE: usize = 3;
+
+pub fn equalize_windows(split: &mut SplitWindow) {
+    for window in split.windows.iter_mut() {
+        window.equalize_windows();
+        window.set_window_size(window.default_window_size());
+    }
+}
+
+pub fn maximize_window(split: &mut SplitWindow, direction: Direction) {
+    let focused_index = split.focused_index;
+
+    for (i, window) in split.windows.iter_mut().enumerate() {
+        if i == focused_index {
+            window.maximize_window(direction);
+
+            if split.direction == direction {
+                window.set_window_size(WindowSize::Default);
+            }
+        } else if split.direction == direction {
+            window.set_window_size(WindowSize::DefaultSize(MINIMIZED_WINDOW_SIZE));
+        }
+    }
+}
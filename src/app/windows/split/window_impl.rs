@@ -1,7 +1,11 @@
-use std::any::{Any, TypeId};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 use crossbeam::channel::Sender;
-use crossterm::event::Event;
+use crossterm::event::{Event, MouseEvent};
 use ratatui::{
     Frame,
     layout::{Direction, Rect},
@@ -9,13 +13,15 @@ use ratatui::{
 
 use crate::{
     app::{
-        App, AppEvent, InputMode, Result,
-        window::{Window, WindowSize},
+        App, AppEvent, InputMode, Result, WindowLayout,
+        window::{Window, WindowContext, WindowSize},
     },
     config::Config,
+    directory_entry::DiffStatus,
+    keymap::Action,
 };
 
-use super::{SplitWindow, management, navigation, render, sizing};
+use super::{SplitWindow, commander, management, mouse, navigation, render, sizing};
 
 impl Window for SplitWindow {
     fn id(&self) -> u32 {
@@ -49,6 +55,42 @@ impl Window for SplitWindow {
         handled
     }
 
+    fn handle_mouse(
+        &mut self,
+        mouse: &MouseEvent,
+        double_click: bool,
+        area: Rect,
+        event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        mouse::handle_mouse(self, mouse, double_click, area, event_tx)
+    }
+
+    fn border_at(&self, column: u16, row: u16, area: Rect) -> Option<(u32, usize)> {
+        mouse::border_at(self, column, row, area)
+    }
+
+    fn drag_border(
+        &mut self,
+        split_id: u32,
+        index: usize,
+        column: u16,
+        row: u16,
+        area: Rect,
+    ) -> bool {
+        mouse::drag_border(self, split_id, index, column, row, area)
+    }
+
+    fn handle_action(
+        &mut self,
+        action: Action,
+        count: Option<usize>,
+        event_tx: &Sender<AppEvent>,
+    ) -> bool {
+        self.windows
+            .get_mut(self.focused_index)
+            .is_some_and(|window| window.handle_action(action, count, event_tx))
+    }
+
     fn reset(&mut self, config: &Config) -> Result<()> {
         for window in self.windows.iter_mut() {
             window.reset(config)?;
@@ -81,10 +123,83 @@ impl Window for SplitWindow {
         management::quit_focused_window(*self)
     }
 
+    fn focused_window_id(&self) -> u32 {
+        self.windows
+            .get(self.focused_index)
+            .map_or(self.id, |window| window.focused_window_id())
+    }
+
+    fn focus_window(&mut self, id: u32) -> bool {
+        navigation::focus_window(self, id)
+    }
+
+    fn window_areas(&self, area: Rect) -> Vec<(u32, Rect)> {
+        self.windows
+            .iter()
+            .zip(render::areas(self, area))
+            .flat_map(|(window, area)| window.window_areas(area))
+            .collect()
+    }
+
+    fn other_pane(&self) -> Option<WindowContext> {
+        commander::other_pane(self)
+    }
+
+    fn window_contexts(&self) -> Vec<WindowContext> {
+        self.windows
+            .iter()
+            .flat_map(|window| window.window_contexts())
+            .collect()
+    }
+
+    fn set_diff(&mut self, id: u32, statuses: Option<HashMap<PathBuf, DiffStatus>>) -> bool {
+        self.windows
+            .iter_mut()
+            .find(|window| window.includes(id))
+            .is_some_and(|window| window.set_diff(id, statuses))
+    }
+
+    fn switch_pane(&mut self) -> bool {
+        commander::switch_pane(self)
+    }
+
     fn get_window_size(&self) -> &WindowSize {
         &self.window_size
     }
 
+    fn set_window_size(&mut self, window_size: WindowSize) {
+        self.window_size = window_size;
+    }
+
+    fn equalize_windows(&mut self) {
+        sizing::equalize_windows(self);
+    }
+
+    fn maximize_window(&mut self, direction: Direction) {
+        sizing::maximize_window(self, direction);
+    }
+
+    fn swap_window(&mut self) -> bool {
+        management::swap_window(self)
+    }
+
+    fn rotate_windows(&mut self) -> bool {
+        management::rotate_windows(self)
+    }
+
+    fn take_focused_window(self: Box<Self>) -> (Box<dyn Window>, Option<Box<dyn Window>>) {
+        management::take_focused_window(*self)
+    }
+
+    fn insert_at_edge(
+        &mut self,
+        window: Box<dyn Window>,
+        direction: Direction,
+        first: bool,
+    ) -> Option<Box<dyn Window>> {
+        management::insert_at_edge(self, window, direction, first)
+    }
+
     fn adjust_window_size(
         &mut self,
         direction: Direction,
@@ -112,6 +227,43 @@ impl Window for SplitWindow {
         management::remove(*self, id)
     }
 
+    fn change_directory(&mut self, directory: &Path) -> Result<bool> {
+        match self.windows.get_mut(self.focused_index) {
+            Some(window) => window.change_directory(directory),
+            None => Ok(false),
+        }
+    }
+
+    fn select(&mut self, path: &Path) -> bool {
+        self.windows
+            .get_mut(self.focused_index)
+            .is_some_and(|window| window.select(path))
+    }
+
+    fn layout(&self) -> Option<WindowLayout> {
+        let windows = self
+            .windows
+            .iter()
+            .filter_map(|window| window.layout())
+            .collect::<Vec<_>>();
+
+        if windows.is_empty() {
+            return None;
+        }
+
+        Some(WindowLayout::Split {
+            direction: self.direction,
+            focused_index: self.focused_index.min(windows.len() - 1),
+            window_size: self.window_size.clone(),
+            windows,
+            commander: self.commander,
+        })
+    }
+
+    fn focused_context(&self) -> Option<WindowContext> {
+        self.windows.get(self.focused_index)?.focused_context()
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -0,0 +1,252 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use ratatui::layout::Direction;
+
+use crate::{
+    app::WindowLayout,
+    data::{SHELL_INIT_FISH, SHELL_INIT_POSIX},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    /// The wrapper function printed by `--init`
+    pub fn init_script(&self) -> &'static str {
+        match self {
+            Self::Bash | Self::Zsh => SHELL_INIT_POSIX,
+            Self::Fish => SHELL_INIT_FISH,
+        }
+    }
+}
+
+/// a file manager cli tool
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Directories to open, a file opens its directory with the file selected
+    paths: Vec<PathBuf>,
+    /// Open the paths side by side, the default for several paths
+    #[arg(short = 'O', conflicts_with = "stacked")]
+    side_by_side: bool,
+    /// Open the paths one above the other
+    #[arg(short = 'o')]
+    stacked: bool,
+    /// Config file to use instead of the one in the config directory
+    #[arg(long, value_name = "FILE", conflicts_with = "no_config")]
+    config: Option<PathBuf>,
+    /// Start with the default config without reading or writing a config file
+    #[arg(long)]
+    no_config: bool,
+    /// Write the log to FILE instead of stderr
+    #[arg(long, value_name = "FILE")]
+    log_file: Option<PathBuf>,
+    /// Command line to run after starting, as if entered after `:`, can be repeated
+    #[arg(long = "cmd", value_name = "COMMAND")]
+    commands: Vec<String>,
+    /// Restore the windows saved with `:mksession NAME`
+    #[arg(long, value_name = "NAME")]
+    session: Option<String>,
+    /// Write the directory focused when quitting to FILE
+    #[arg(long, value_name = "FILE")]
+    choosedir: Option<PathBuf>,
+    /// Print a shell function that changes to the directory arfima quits in
+    #[arg(long, value_name = "SHELL")]
+    init: Option<Shell>,
+    /// Pick a file instead of opening it, writing its path to FILE or to stdout for `-`
+    #[arg(long, value_name = "FILE", conflicts_with = "choosefiles")]
+    choosefile: Option<PathBuf>,
+    /// Like --choosefile, picking the marked entries when there are any
+    #[arg(long, value_name = "FILE")]
+    choosefiles: Option<PathBuf>,
+    /// Separate picked paths with NUL instead of newlines
+    #[arg(short = '0', long)]
+    print0: bool,
+    /// Send a command line, or a `?directory`, `?selected`, `?marked` or `?context` query, to a
+    /// running instance and print the JSON answer, can be repeated
+    #[arg(long, value_name = "COMMAND")]
+    remote: Vec<String>,
+    /// Socket of the instance --remote talks to, the most recently started one by default
+    #[arg(long, value_name = "PATH", requires = "remote")]
+    socket: Option<PathBuf>,
+}
+
+/// The directory to show for `path` and the entry to select in it
+fn resolve_path(path: &Path) -> Result<(PathBuf, Option<PathBuf>), String> {
+    let path = path
+        .canonicalize()
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+
+    if path.is_dir() {
+        return Ok((path, None));
+    }
+
+    match path.parent() {
+        Some(parent) => Ok((parent.to_owned(), Some(path))),
+        None => Err(format!("{} has no parent directory", path.display())),
+    }
+}
+
+impl Cli {
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    pub fn config(&self) -> Option<&Path> {
+        self.config.as_deref()
+    }
+
+    pub fn no_config(&self) -> bool {
+        self.no_config
+    }
+
+    pub fn log_file(&self) -> Option<&Path> {
+        self.log_file.as_deref()
+    }
+
+    pub fn commands(&self) -> &[String] {
+        &self.commands
+    }
+
+    pub fn session(&self) -> Option<&str> {
+        self.session.as_deref()
+    }
+
+    pub fn choosedir(&self) -> Option<&Path> {
+        self.choosedir.as_deref()
+    }
+
+    pub fn init(&self) -> Option<Shell> {
+        self.init
+    }
+
+    /// Where picked paths are written and whether several can be picked
+    pub fn choose(&self) -> Option<(&Path, bool)> {
+        match (&self.choosefile, &self.choosefiles) {
+            (Some(path), _) => Some((path, false)),
+            (None, Some(path)) => Some((path, true)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn print0(&self) -> bool {
+        self.print0
+    }
+
+    pub fn remote(&self) -> &[String] {
+        &self.remote
+    }
+
+    pub fn socket(&self) -> Option<&Path> {
+        self.socket.as_deref()
+    }
+
+    /// Windows for the paths given, `None` without paths
+    pub fn layout(&self) -> Result<Option<WindowLayout>, String> {
+        let mut windows = self
+            .paths
+            .iter()
+            .map(|path| {
+                resolve_path(path).map(|(directory, selected)| WindowLayout::FileManager {
+                    directory,
+                    selected,
+                    window_size: Default::default(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(match windows.len() {
+            0 => None,
+            1 => windows.pop(),
+            _ => Some(WindowLayout::Split {
+                direction: if self.stacked {
+                    Direction::Vertical
+                } else {
+                    Direction::Horizontal
+                },
+                focused_index: 0,
+                window_size: Default::default(),
+                windows,
+                commander: false,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        let cli =
+            Cli::try_parse_from(["arfima", "-o", "--cmd", "set nu", "--cmd", "cd ~", "a", "b"])
+                .unwrap();
+
+        assert!(cli.stacked);
+        assert_eq!(cli.commands(), ["set nu", "cd ~"]);
+        assert_eq!(cli.paths(), [PathBuf::from("a"), PathBuf::from("b")]);
+
+        assert!(Cli::try_parse_from(["arfima", "-o", "-O"]).is_err());
+        assert!(
+            Cli::try_parse_from(["arfima", "--choosefile", "-", "--choosefiles", "-"]).is_err()
+        );
+        assert_eq!(
+            Cli::try_parse_from(["arfima", "--choosefiles", "-", "-0"])
+                .unwrap()
+                .choose(),
+            Some((Path::new("-"), true))
+        );
+        assert_eq!(
+            Cli::try_parse_from(["arfima", "--init", "fish"])
+                .unwrap()
+                .init(),
+            Some(Shell::Fish)
+        );
+        assert!(Cli::try_parse_from(["arfima", "--config", "a", "--no-config"]).is_err());
+    }
+
+    #[test]
+    fn test_layout_selects_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let file = directory.path().join("file");
+        std::fs::write(&file, "").unwrap();
+
+        let cli = Cli::try_parse_from([
+            "arfima".into(),
+            "-o".into(),
+            directory.path().to_owned(),
+            file.clone(),
+        ])
+        .unwrap();
+
+        let Ok(Some(WindowLayout::Split {
+            direction, windows, ..
+        })) = cli.layout()
+        else {
+            panic!("expected a split");
+        };
+
+        assert_eq!(direction, Direction::Vertical);
+        assert!(matches!(
+            &windows[1],
+            WindowLayout::FileManager { selected: Some(selected), .. }
+                if *selected == file.canonicalize().unwrap()
+        ));
+
+        let missing = Cli::try_parse_from(["arfima", "/does/not/exist"]).unwrap();
+        assert!(missing.layout().is_err());
+    }
+}
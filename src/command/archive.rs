@@ -0,0 +1,60 @@
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::{eof, opt, rest, verify},
+    sequence::preceded,
+};
+
+/// `:compress <name>`, the extension of `name` tells the kind of archive
+pub fn parse_compress_command(input: &str) -> IResult<&str, String> {
+    let (input, _) = tag("compress")(input)?;
+    let (input, name) = preceded(
+        multispace1,
+        verify(rest, |name: &str| !name.trim().is_empty()),
+    )(input)?;
+    let (input, _) = eof(input)?;
+
+    Ok((input, name.trim().to_string()))
+}
+
+/// `:extract [dest]`, without a destination it extracts next to the archive
+pub fn parse_extract_command(input: &str) -> IResult<&str, Option<String>> {
+    let (input, _) = tag("extract")(input)?;
+    let (input, destination) = opt(preceded(multispace1, rest))(input)?;
+    let (input, _) = eof(input)?;
+
+    let destination = destination
+        .map(str::trim)
+        .filter(|destination| !destination.is_empty())
+        .map(str::to_string);
+
+    Ok((input, destination))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_compress_command() {
+        assert_eq!(
+            parse_compress_command("compress  backup.tar.zst "),
+            Ok(("", "backup.tar.zst".to_string()))
+        );
+        assert!(parse_compress_command("compress").is_err());
+        assert!(parse_compress_command("compress   ").is_err());
+        assert!(parse_compress_command("compressed a.zip").is_err());
+    }
+
+    #[test]
+    fn test_parse_extract_command() {
+        assert_eq!(parse_extract_command("extract"), Ok(("", None)));
+        assert_eq!(parse_extract_command("extract  "), Ok(("", None)));
+        assert_eq!(
+            parse_extract_command("extract ~/out dir"),
+            Ok(("", Some("~/out dir".to_string())))
+        );
+        assert!(parse_extract_command("extractall").is_err());
+    }
+}
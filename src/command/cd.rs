@@ -0,0 +1,21 @@
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::{eof, opt, rest},
+    sequence::preceded,
+};
+
+/// `:cd [path]`, without a path it goes to the home directory
+pub fn parse_cd_command(input: &str) -> IResult<&str, Option<String>> {
+    let (input, _) = tag("cd")(input)?;
+    let (input, path) = opt(preceded(multispace1, rest))(input)?;
+    let (input, _) = eof(input)?;
+
+    let path = path
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(str::to_string);
+
+    Ok((input, path))
+}
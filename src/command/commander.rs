@@ -0,0 +1,22 @@
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::{eof, opt, rest},
+    sequence::preceded,
+};
+
+/// `:commander [path]`, two panes with the focused directory on the left and `path` on the right
+pub fn parse_commander_command(input: &str) -> IResult<&str, Option<String>> {
+    let (input, _) = alt((tag("commander"), tag("cmdr")))(input)?;
+    let (input, path) = opt(preceded(multispace1, rest))(input)?;
+    let (input, _) = eof(input)?;
+
+    let path = path
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(str::to_string);
+
+    Ok((input, path))
+}
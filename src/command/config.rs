@@ -0,0 +1,8 @@
+use nom::{IResult, bytes::complete::tag, combinator::eof, sequence::terminated};
+
+/// `:config`, edits the config file
+pub fn parse_config_command(input: &str) -> IResult<&str, ()> {
+    let (input, _) = terminated(tag("config"), eof)(input)?;
+
+    Ok((input, ()))
+}
@@ -0,0 +1,57 @@
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{eof, map, opt, value},
+    sequence::{preceded, terminated},
+};
+
+/// What `:diff` and the commands working on it do
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffCommand {
+    /// `:diff`, or `:diff!` to also compare the contents of files of the same size
+    Start { content: bool },
+    /// `:diffoff`
+    Off,
+    /// `:diffput` copies into the other window, `:diffget` into the focused one
+    Sync { put: bool },
+}
+
+pub fn parse_diff_command(input: &str) -> IResult<&str, DiffCommand> {
+    terminated(
+        alt((
+            value(DiffCommand::Off, tag("diffoff")),
+            value(DiffCommand::Sync { put: true }, tag("diffput")),
+            value(DiffCommand::Sync { put: false }, tag("diffget")),
+            map(preceded(tag("diff"), opt(tag("!"))), |bang| {
+                DiffCommand::Start {
+                    content: bang.is_some(),
+                }
+            }),
+        )),
+        eof,
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_diff_command() {
+        assert_eq!(
+            parse_diff_command("diff"),
+            Ok(("", DiffCommand::Start { content: false }))
+        );
+        assert_eq!(
+            parse_diff_command("diff!"),
+            Ok(("", DiffCommand::Start { content: true }))
+        );
+        assert_eq!(parse_diff_command("diffoff"), Ok(("", DiffCommand::Off)));
+        assert_eq!(
+            parse_diff_command("diffget"),
+            Ok(("", DiffCommand::Sync { put: false }))
+        );
+        assert!(parse_diff_command("diffs").is_err());
+    }
+}
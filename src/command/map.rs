@@ -0,0 +1,64 @@
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::multispace1,
+    combinator::{map, rest, value, verify},
+    sequence::terminated,
+};
+
+/// `:nmap {lhs} {rhs}` and its non-recursive `:nnoremap` form
+pub struct MapCommand {
+    lhs: String,
+    rhs: String,
+    noremap: bool,
+}
+
+impl MapCommand {
+    pub fn lhs(&self) -> &str {
+        &self.lhs
+    }
+
+    pub fn rhs(&self) -> &str {
+        &self.rhs
+    }
+
+    pub fn noremap(&self) -> bool {
+        self.noremap
+    }
+}
+
+fn parse_lhs(input: &str) -> IResult<&str, String> {
+    map(take_till1(char::is_whitespace), str::to_string)(input)
+}
+
+pub fn parse_map_command(input: &str) -> IResult<&str, MapCommand> {
+    let (input, noremap) = terminated(
+        alt((
+            value(true, tag("nnoremap")),
+            value(true, tag("noremap")),
+            value(false, tag("nmap")),
+            value(false, tag("map")),
+        )),
+        multispace1,
+    )(input)?;
+    let (input, lhs) = parse_lhs(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, rhs) = verify(rest, |s: &str| !s.trim().is_empty())(input)?;
+
+    Ok((
+        input,
+        MapCommand {
+            lhs,
+            rhs: rhs.trim_end().to_string(),
+            noremap,
+        },
+    ))
+}
+
+/// `:nunmap {lhs}`
+pub fn parse_unmap_command(input: &str) -> IResult<&str, String> {
+    let (input, _) = terminated(alt((tag("nunmap"), tag("unmap"))), multispace1)(input)?;
+
+    parse_lhs(input)
+}
@@ -1,30 +1,77 @@
+mod archive;
+mod cd;
+mod commander;
+mod config;
+mod diff;
 mod error;
+mod map;
 mod quit;
 pub mod result;
 mod save;
+mod session;
 mod set;
+mod shell;
+mod source;
+mod user_command;
 
+use archive::{parse_compress_command, parse_extract_command};
+use cd::parse_cd_command;
+use commander::parse_commander_command;
+use config::parse_config_command;
+pub use diff::DiffCommand;
+use diff::parse_diff_command;
 pub use error::Error;
+pub use map::MapCommand;
+use map::{parse_map_command, parse_unmap_command};
 pub use quit::QuitCommand;
 use quit::parse_quit_command;
 use result::Result;
 use save::parse_save_command;
+use session::parse_make_session_command;
 use set::parse_set_command;
-pub use set::{BooleanOption, SetCommand, SetOption};
+pub use set::{AssignOperator, SetCommand};
+use shell::parse_shell_command;
+use source::parse_source_command;
+use user_command::parse_define_command;
+pub use user_command::{DefineCommand, expand_user_command};
 
 use nom::{IResult, branch::alt, combinator::map};
 
 pub enum Command {
-    Set(SetCommand),
+    Set(Vec<SetCommand>),
     Quit(QuitCommand),
     Save(()),
+    Shell(String),
+    Cd(Option<String>),
+    Map(MapCommand),
+    Unmap(String),
+    DefineCommand(DefineCommand),
+    Source(Option<String>),
+    Config,
+    MakeSession(Option<String>),
+    Commander(Option<String>),
+    Diff(DiffCommand),
+    Compress(String),
+    Extract(Option<String>),
 }
 
 fn parse_any_command(input: &str) -> IResult<&str, Command> {
     alt((
         map(parse_set_command, Command::Set),
+        map(parse_source_command, Command::Source),
+        map(parse_config_command, |_| Command::Config),
+        map(parse_make_session_command, Command::MakeSession),
+        map(parse_commander_command, Command::Commander),
+        map(parse_diff_command, Command::Diff),
+        map(parse_compress_command, Command::Compress),
+        map(parse_extract_command, Command::Extract),
         map(parse_quit_command, Command::Quit),
         map(parse_save_command, Command::Save),
+        map(parse_shell_command, Command::Shell),
+        map(parse_cd_command, Command::Cd),
+        map(parse_map_command, Command::Map),
+        map(parse_unmap_command, Command::Unmap),
+        map(parse_define_command, Command::DefineCommand),
     ))(input)
 }
 
@@ -1,22 +1,73 @@
-use nom::{IResult, branch::alt, bytes::complete::tag, combinator::map};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{eof, map},
+    sequence::terminated,
+};
 
 pub struct QuitCommand {
     all: bool,
+    choose_directory: bool,
 }
 
 impl QuitCommand {
     pub fn new(all: bool) -> Self {
-        Self { all }
+        Self {
+            all,
+            choose_directory: false,
+        }
+    }
+
+    /// `:cdq`, quits everything from the focused window, whose directory `--choosedir` gets
+    pub fn choosing_directory() -> Self {
+        Self {
+            all: true,
+            choose_directory: true,
+        }
     }
 
     pub fn all(&self) -> bool {
         self.all
     }
+
+    pub fn choose_directory(&self) -> bool {
+        self.choose_directory
+    }
 }
 
 pub fn parse_quit_command(input: &str) -> IResult<&str, QuitCommand> {
-    alt((
-        map(alt((tag("qa"), tag("quitall"))), |_| QuitCommand::new(true)),
-        map(alt((tag("q"), tag("quit"))), |_| QuitCommand::new(false)),
-    ))(input)
+    terminated(
+        alt((
+            map(alt((tag("cdquit"), tag("cdq"))), |_| {
+                QuitCommand::choosing_directory()
+            }),
+            map(alt((tag("quitall"), tag("qa"))), |_| QuitCommand::new(true)),
+            map(alt((tag("quit"), tag("q"))), |_| QuitCommand::new(false)),
+        )),
+        eof,
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quit_command() {
+        for (input, all, choose_directory) in [
+            ("q", false, false),
+            ("quit", false, false),
+            ("qa", true, false),
+            ("quitall", true, false),
+            ("cdq", true, true),
+            ("cdquit", true, true),
+        ] {
+            let (_, command) = parse_quit_command(input).unwrap();
+            assert_eq!(command.all(), all);
+            assert_eq!(command.choose_directory(), choose_directory);
+        }
+
+        assert!(parse_quit_command("quux").is_err());
+    }
 }
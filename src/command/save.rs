@@ -1,5 +1,29 @@
-use nom::{IResult, branch::alt, bytes::complete::tag, combinator::map};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{eof, map},
+    sequence::terminated,
+};
 
+/// `:save`, `:write` or their short forms `:s` and `:w`, writes the config file
 pub fn parse_save_command(input: &str) -> IResult<&str, ()> {
-    map(alt((tag("s"), tag("save"))), |_| ())(input)
+    map(
+        terminated(alt((tag("save"), tag("write"), tag("s"), tag("w"))), eof),
+        |_| (),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_save_command() {
+        for input in ["s", "save", "w", "write"] {
+            assert_eq!(parse_save_command(input), Ok(("", ())));
+        }
+        assert!(parse_save_command("wq").is_err());
+        assert!(parse_save_command("sort").is_err());
+    }
 }
@@ -0,0 +1,22 @@
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::{eof, opt, rest},
+    sequence::preceded,
+};
+
+/// `:mksession [name]`, without a name it saves the default session
+pub fn parse_make_session_command(input: &str) -> IResult<&str, Option<String>> {
+    let (input, _) = alt((tag("mksession"), tag("mks")))(input)?;
+    let (input, name) = opt(preceded(multispace1, rest))(input)?;
+    let (input, _) = eof(input)?;
+
+    let name = name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+
+    Ok((input, name))
+}
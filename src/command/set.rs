@@ -2,73 +2,142 @@ use nom::{
     IResult,
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, multispace1},
-    combinator::{map, opt},
+    character::complete::multispace1,
+    combinator::{map, rest, verify},
 };
 
-pub enum BooleanOption {
-    Number,
-    RelativeNumber,
-}
-
-pub enum SetOption {
-    HistorySize(usize),
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AssignOperator {
+    Set,
+    Add,
+    Remove,
 }
 
+/// One argument of `:set`, option names are resolved when the command is executed
+#[derive(Debug, PartialEq)]
 pub enum SetCommand {
-    Enable(BooleanOption),
-    Disable(BooleanOption),
-    Toggle(BooleanOption),
-    Set(SetOption),
+    /// `name`, enables a boolean and shows any other option
+    Bare(String),
+    /// `name!` or `invname`
+    Toggle(String),
+    /// `name&`
+    Reset(String),
+    /// `name?`
+    Query(String),
+    /// `name=value`, `name+=value` or `name-=value`
+    Assign {
+        name: String,
+        operator: AssignOperator,
+        value: String,
+    },
 }
 
-fn parse_history_size_assignment(input: &str) -> IResult<&str, SetOption> {
-    let (input, _) = tag("history_size")(input)?;
-    let (input, _) = alt((tag("="), multispace1))(input)?;
-    let (input, digits) = digit1(input)?;
+/// Splits on whitespace that is not escaped with a backslash, so `\ ` keeps spaces in values
+fn split_arguments(input: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(next) if next.is_whitespace() || next == '\\' => current.push(next),
+                Some(next) => {
+                    current.push(c);
+                    current.push(next);
+                }
+                None => current.push(c),
+            },
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    arguments.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
 
-    let value: usize = digits.parse().map_err(|_| {
-        nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Digit))
-    })?;
+    if !current.is_empty() {
+        arguments.push(current);
+    }
 
-    Ok((input, SetOption::HistorySize(value)))
+    arguments
 }
 
-fn parse_boolean_option(input: &str) -> IResult<&str, BooleanOption> {
-    alt((
-        map(alt((tag("number"), tag("nu"))), |_| BooleanOption::Number),
-        map(alt((tag("relativenumber"), tag("rnu"))), |_| {
-            BooleanOption::RelativeNumber
-        }),
-    ))(input)
-}
+fn parse_argument(argument: String) -> SetCommand {
+    if let Some(index) = argument.find('=') {
+        let (name, value) = argument.split_at(index);
+        let value = value[1..].to_string();
 
-fn parse_boolean_set_command(input: &str) -> IResult<&str, SetCommand> {
-    let (input, is_no) = opt(tag("no"))(input)?;
-    let (input, name) = parse_boolean_option(input)?;
-    let (input, is_toggle) = opt(tag("!"))(input)?;
-
-    let command = match (is_no, is_toggle) {
-        (Some(_), None) => SetCommand::Disable(name),
-        (None, None) => SetCommand::Enable(name),
-        (None, Some(_)) => SetCommand::Toggle(name),
-        _ => {
-            return Err(nom::Err::Failure(nom::error::Error::new(
-                input,
-                nom::error::ErrorKind::Tag,
-            )));
-        }
-    };
+        let (name, operator) = if let Some(name) = name.strip_suffix('+') {
+            (name, AssignOperator::Add)
+        } else if let Some(name) = name.strip_suffix('-') {
+            (name, AssignOperator::Remove)
+        } else {
+            (name, AssignOperator::Set)
+        };
 
-    Ok((input, command))
+        return SetCommand::Assign {
+            name: name.to_string(),
+            operator,
+            value,
+        };
+    }
+
+    if let Some(name) = argument.strip_suffix('?') {
+        SetCommand::Query(name.to_string())
+    } else if let Some(name) = argument.strip_suffix('&') {
+        SetCommand::Reset(name.to_string())
+    } else if let Some(name) = argument.strip_suffix('!') {
+        SetCommand::Toggle(name.to_string())
+    } else {
+        SetCommand::Bare(argument)
+    }
 }
 
-pub fn parse_set_command(input: &str) -> IResult<&str, SetCommand> {
+pub fn parse_set_command(input: &str) -> IResult<&str, Vec<SetCommand>> {
     let (input, _) = alt((tag("set"), tag("se")))(input)?;
     let (input, _) = multispace1(input)?;
 
-    alt((
-        map(parse_history_size_assignment, SetCommand::Set),
-        parse_boolean_set_command,
-    ))(input)
+    map(verify(rest, |s: &str| !s.trim().is_empty()), |s: &str| {
+        split_arguments(s).into_iter().map(parse_argument).collect()
+    })(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_set_arguments() {
+        let (_, commands) =
+            parse_set_command("set nu rnu! hi& editor? history_size=20 other_paths+=~/a\\ b")
+                .unwrap();
+
+        assert_eq!(
+            commands,
+            vec![
+                SetCommand::Bare("nu".into()),
+                SetCommand::Toggle("rnu".into()),
+                SetCommand::Reset("hi".into()),
+                SetCommand::Query("editor".into()),
+                SetCommand::Assign {
+                    name: "history_size".into(),
+                    operator: AssignOperator::Set,
+                    value: "20".into(),
+                },
+                SetCommand::Assign {
+                    name: "other_paths".into(),
+                    operator: AssignOperator::Add,
+                    value: "~/a b".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_requires_arguments() {
+        assert!(parse_set_command("set ").is_err());
+        assert!(parse_set_command("set").is_err());
+    }
 }
@@ -0,0 +1,15 @@
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::multispace0,
+    combinator::{map, rest, verify},
+};
+
+pub fn parse_shell_command(input: &str) -> IResult<&str, String> {
+    let (input, _) = tag("!")(input)?;
+    let (input, _) = multispace0(input)?;
+
+    map(verify(rest, |s: &str| !s.trim().is_empty()), |s: &str| {
+        s.trim_end().to_string()
+    })(input)
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::multispace1,
+    combinator::{rest, verify},
+    sequence::terminated,
+};
+
+/// `:command {Name} {replacement}`, user command names start with an uppercase letter so they
+/// never shadow built-in commands
+pub struct DefineCommand {
+    name: String,
+    replacement: String,
+}
+
+impl DefineCommand {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+}
+
+fn is_command_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+pub fn parse_define_command(input: &str) -> IResult<&str, DefineCommand> {
+    let (input, _) = terminated(alt((tag("command"), tag("com"))), multispace1)(input)?;
+    let (input, name) = verify(
+        take_while1(|c: char| c.is_ascii_alphanumeric()),
+        is_command_name,
+    )(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, replacement) = verify(rest, |s: &str| !s.trim().is_empty())(input)?;
+
+    Ok((
+        input,
+        DefineCommand {
+            name: name.to_string(),
+            replacement: replacement.trim_end().to_string(),
+        },
+    ))
+}
+
+/// Replaces a leading user command name with its definition, keeping any arguments after it
+pub fn expand_user_command(input: &str, commands: &BTreeMap<String, String>) -> Option<String> {
+    let input = input.trim_start();
+    let (name, arguments) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+
+    if !is_command_name(name) {
+        return None;
+    }
+
+    let replacement = commands.get(name)?;
+    let arguments = arguments.trim();
+
+    if arguments.is_empty() {
+        Some(replacement.clone())
+    } else {
+        Some(format!("{replacement} {arguments}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_define_command() {
+        let (_, command) = parse_define_command("command Proj cd ~/work/proj").unwrap();
+
+        assert_eq!(command.name(), "Proj");
+        assert_eq!(command.replacement(), "cd ~/work/proj");
+        assert!(parse_define_command("command proj cd ~").is_err());
+    }
+
+    #[test]
+    fn test_expand_user_command() {
+        let commands = BTreeMap::from([("Proj".to_string(), "cd ~/work/proj".to_string())]);
+
+        assert_eq!(
+            expand_user_command("Proj", &commands).as_deref(),
+            Some("cd ~/work/proj")
+        );
+        assert_eq!(
+            expand_user_command("Proj sub", &commands).as_deref(),
+            Some("cd ~/work/proj sub")
+        );
+        assert_eq!(expand_user_command("Other", &commands), None);
+        assert_eq!(expand_user_command("cd ~", &commands), None);
+    }
+}
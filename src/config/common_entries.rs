@@ -13,6 +13,7 @@ where
 }
 
 #[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CommonEntriesConfig {
     #[serde(default)]
     user_dirs: bool,
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// How file sizes are counted in the size column
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeUnits {
+    /// Powers of 1024
+    #[default]
+    Binary,
+    /// Powers of 1000
+    Decimal,
+}
+
+impl SizeUnits {
+    pub const NAMES: &[&str] = &["binary", "decimal"];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::Decimal => "decimal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "binary" => Some(Self::Binary),
+            "decimal" => Some(Self::Decimal),
+            _ => None,
+        }
+    }
+
+    pub fn base(&self) -> f64 {
+        match self {
+            Self::Binary => 1024.0,
+            Self::Decimal => 1000.0,
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisplayConfig {
+    #[serde(default)]
+    size_units: SizeUnits,
+}
+
+impl DisplayConfig {
+    pub fn size_units(&self) -> SizeUnits {
+        self.size_units
+    }
+
+    pub fn set_size_units(&mut self, size_units: SizeUnits) {
+        self.size_units = size_units;
+    }
+}
@@ -0,0 +1,66 @@
+use toml_edit::{DocumentMut, Item, Table};
+
+use super::Result;
+
+fn is_empty_table(item: &Item) -> bool {
+    item.as_table_like()
+        .is_some_and(|table| table.iter().all(|(_, item)| is_empty_table(item)))
+}
+
+/// Makes `existing` hold the values of `new`, keeping the comments and layout of what is already
+/// there
+fn merge_table(existing: &mut Table, new: &Table) {
+    let removed: Vec<String> = existing
+        .iter()
+        .filter(|(key, _)| !new.contains_key(key))
+        .map(|(key, _)| key.to_string())
+        .collect();
+    for key in removed {
+        existing.remove(&key);
+    }
+
+    for (key, item) in new.iter() {
+        match (existing.get_mut(key), item) {
+            (Some(Item::Table(old)), Item::Table(table)) => merge_table(old, table),
+            (Some(Item::Value(old)), Item::Value(value)) => {
+                if old.to_string().trim() != value.to_string().trim() {
+                    let decor = old.decor().clone();
+                    *old = value.clone();
+                    *old.decor_mut() = decor;
+                }
+            }
+            (Some(old), _) => *old = item.clone(),
+            // empty sections only exist in the file when written there
+            (None, _) if is_empty_table(item) => {}
+            (None, _) => {
+                existing.insert(key, item.clone());
+            }
+        }
+    }
+}
+
+/// The config file `existing` rewritten with the values serialized in `new`, comments kept
+pub fn update_document(existing: &str, new: &str) -> Result<String> {
+    let mut document = existing.parse::<DocumentMut>().unwrap_or_default();
+    let new = new.parse::<DocumentMut>()?;
+
+    merge_table(document.as_table_mut(), new.as_table());
+
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_keeps_comments() {
+        let existing = "# header\n\n[number]\n# shown next to entries\nactive = false # inline\nrelative = false\n\n[keys.normal]\n\"x\" = \"remove\"\n";
+        let new = "[number]\nactive = true\nrelative = false\n\n[keys.normal]\n\n[commands]\n";
+
+        assert_eq!(
+            update_document(existing, new).unwrap(),
+            "# header\n\n[number]\n# shown next to entries\nactive = true # inline\nrelative = false\n\n[keys.normal]\n"
+        );
+    }
+}
@@ -1,10 +1,34 @@
-use std::{fmt::Display, io};
+use std::{fmt::Display, io, path::PathBuf};
+
+use crate::utils::str::closest_match;
 
 #[derive(Debug)]
 pub enum Error {
     Io(io::Error),
     Parse(toml::de::Error),
+    /// A parse or type error located in a config file
+    Invalid {
+        path: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The config was not loaded from a file, so there is nowhere to save it
+    NoPath,
     Serialization(toml::ser::Error),
+    /// The config serialized for saving could not be read back as a document
+    Document(toml_edit::TomlError),
+    UnknownOption(String),
+    InvalidOptionValue {
+        option: &'static str,
+        value: String,
+        expected: String,
+    },
+    NotABoolean(&'static str),
+    UnsupportedOperator {
+        option: &'static str,
+        operator: &'static str,
+    },
 }
 
 impl Display for Error {
@@ -12,13 +36,76 @@ impl Display for Error {
         match self {
             Self::Io(e) => write!(f, "IO error: {e}"),
             Self::Parse(e) => write!(f, "Parse error: {e}"),
+            Self::Invalid {
+                path,
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{line}:{column}: {message}", path.display()),
+            Self::NoPath => write!(f, "The config has no file, :source one before saving"),
             Self::Serialization(e) => write!(f, "Serialization error: {e}"),
+            Self::Document(e) => write!(f, "Serialization error: {e}"),
+            Self::UnknownOption(name) => write!(f, "Unknown option: {name}"),
+            Self::InvalidOptionValue {
+                option,
+                value,
+                expected,
+            } => write!(
+                f,
+                "Invalid value '{value}' for {option}, expected {expected}"
+            ),
+            Self::NotABoolean(option) => write!(f, "{option} is not a boolean option"),
+            Self::UnsupportedOperator { option, operator } => {
+                write!(f, "Cannot use {operator} with {option}")
+            }
         }
     }
 }
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// Locates a TOML error in `contents` and adds a suggestion for misspelled keys or values
+    pub fn invalid(path: PathBuf, contents: &str, error: toml::de::Error) -> Self {
+        let Some(span) = error.span() else {
+            return Self::Parse(error);
+        };
+
+        let before = &contents[..span.start.min(contents.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+            + 1;
+
+        let message = error.message().trim().to_string();
+        let message = match suggestion(&message) {
+            Some(suggestion) => format!("{message}, did you mean `{suggestion}`?"),
+            None => message,
+        };
+
+        Self::Invalid {
+            path,
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+/// serde reports unknown fields and variants as "unknown field `x`, expected one of `a`, `b`"
+fn suggestion(message: &str) -> Option<&str> {
+    if !message.starts_with("unknown field") && !message.starts_with("unknown variant") {
+        return None;
+    }
+
+    let mut quoted = message.split('`').skip(1).step_by(2);
+    let unknown = quoted.next()?;
+
+    closest_match(unknown, quoted)
+}
+
 impl From<io::Error> for Error {
     fn from(value: io::Error) -> Self {
         Self::Io(value)
@@ -36,3 +123,9 @@ impl From<toml::ser::Error> for Error {
         Self::Serialization(value)
     }
 }
+
+impl From<toml_edit::TomlError> for Error {
+    fn from(value: toml_edit::TomlError) -> Self {
+        Self::Document(value)
+    }
+}
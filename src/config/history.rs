@@ -7,6 +7,7 @@ fn default_size() -> usize {
 }
 
 #[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct HistoryConfig {
     #[serde(default = "default_size")]
     size: usize,
@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::keymap::Mapping;
+
+/// User key bindings, e.g.
+///
+/// ```toml
+/// [keys.normal]
+/// "x" = "remove"
+/// "<C-w>s" = "split_horizontal"
+/// "q" = "nop"
+/// "gh" = { keys = ":cd ~<CR>" }
+/// ```
+#[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeysConfig {
+    #[serde(default)]
+    normal: BTreeMap<String, Mapping>,
+}
+
+impl KeysConfig {
+    pub fn normal(&self) -> &BTreeMap<String, Mapping> {
+        &self.normal
+    }
+
+    pub fn mut_normal(&mut self) -> &mut BTreeMap<String, Mapping> {
+        &mut self.normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keymap::Action;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip_actions_and_keys() {
+        let mut keys = KeysConfig::default();
+        keys.mut_normal()
+            .insert("x".into(), Mapping::Action(Action::Remove));
+        keys.mut_normal().insert(
+            "gh".into(),
+            Mapping::Keys {
+                keys: ":cd ~<CR>".parse().unwrap(),
+                noremap: true,
+            },
+        );
+
+        let contents = toml::to_string(&keys).unwrap();
+        let parsed: KeysConfig = toml::from_str(&contents).unwrap();
+
+        assert_eq!(parsed.normal(), keys.normal());
+    }
+
+    #[test]
+    fn test_invalid_sequence_is_rejected() {
+        let contents = "[normal]\ngh = { keys = \"<Nope>\" }\n";
+
+        assert!(toml::from_str::<KeysConfig>(contents).is_err());
+    }
+}
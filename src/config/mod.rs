@@ -1,32 +1,77 @@
 mod common_entries;
+mod display;
+mod document;
 mod error;
 mod history;
+mod keys;
 mod number;
+mod opener;
+mod options;
+mod session;
 
-use std::{fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
 
+use crate::data::DEFAULT_CONFIG;
+
 use common_entries::CommonEntriesConfig;
+use display::DisplayConfig;
+pub use display::SizeUnits;
 pub use error::Error;
 use history::HistoryConfig;
+pub use keys::KeysConfig;
 use number::NumberConfig;
+pub use opener::{OpenerConfig, OpenerRule};
+pub use options::{ConfigOption, OptionKind, OptionValue, find_option};
+use session::SessionConfig;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
-    #[serde(default, skip_serializing)]
+    #[serde(skip)]
     path: PathBuf,
     #[serde(default)]
     number: NumberConfig,
     #[serde(default)]
+    display: DisplayConfig,
+    #[serde(default)]
     common_entries: CommonEntriesConfig,
     #[serde(default)]
     history: HistoryConfig,
+    #[serde(default)]
+    opener: OpenerConfig,
+    #[serde(default)]
+    session: SessionConfig,
+    #[serde(default)]
+    keys: KeysConfig,
+    /// User commands defined with `:command`, name to the command line it runs
+    #[serde(default)]
+    commands: BTreeMap<String, String>,
 }
 
 impl Config {
+    /// Parses `contents` read from `path`, errors point at the line and column in the file
+    pub fn parse(path: PathBuf, contents: &str) -> Result<Self> {
+        let mut config = toml::from_str::<Config>(contents)
+            .map_err(|e| Error::invalid(path.clone(), contents, e))?;
+
+        config.path = path;
+
+        Ok(config)
+    }
+
+    /// The file the config was loaded from and is saved to, empty for the default config
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn number(&self) -> &NumberConfig {
         &self.number
     }
@@ -35,6 +80,14 @@ impl Config {
         &mut self.number
     }
 
+    pub fn display(&self) -> &DisplayConfig {
+        &self.display
+    }
+
+    pub fn mut_display(&mut self) -> &mut DisplayConfig {
+        &mut self.display
+    }
+
     pub fn common_entries(&self) -> &CommonEntriesConfig {
         &self.common_entries
     }
@@ -51,8 +104,62 @@ impl Config {
         &mut self.history
     }
 
+    pub fn opener(&self) -> &OpenerConfig {
+        &self.opener
+    }
+
+    pub fn mut_opener(&mut self) -> &mut OpenerConfig {
+        &mut self.opener
+    }
+
+    pub fn session(&self) -> &SessionConfig {
+        &self.session
+    }
+
+    pub fn mut_session(&mut self) -> &mut SessionConfig {
+        &mut self.session
+    }
+
+    pub fn keys(&self) -> &KeysConfig {
+        &self.keys
+    }
+
+    pub fn mut_keys(&mut self) -> &mut KeysConfig {
+        &mut self.keys
+    }
+
+    pub fn commands(&self) -> &BTreeMap<String, String> {
+        &self.commands
+    }
+
+    pub fn mut_commands(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.commands
+    }
+
+    /// Writes the commented default config to `path`, creating its directory, and loads it
+    pub fn write_default(path: PathBuf) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, DEFAULT_CONFIG)?;
+
+        Self::parse(path, DEFAULT_CONFIG)
+    }
+
+    /// Writes the config to its file, keeping the comments and layout of what the file holds
     pub fn save(&self) -> Result<()> {
-        let contents: String = self.try_into()?;
+        if self.path.as_os_str().is_empty() {
+            return Err(Error::NoPath);
+        }
+
+        let serialized: String = self.try_into()?;
+        let contents = document::update_document(
+            &fs::read_to_string(&self.path).unwrap_or_default(),
+            &serialized,
+        )?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
         fs::write(self.path.as_path(), contents.as_bytes())?;
 
         Ok(())
@@ -63,13 +170,9 @@ impl TryFrom<PathBuf> for Config {
     type Error = Error;
 
     fn try_from(value: PathBuf) -> std::result::Result<Self, Self::Error> {
-        let contents = fs::read_to_string(value.clone())?;
-
-        let mut config = toml::from_str::<Config>(&contents)?;
+        let contents = fs::read_to_string(&value)?;
 
-        config.path = value;
-
-        Ok(config)
+        Self::parse(value, &contents)
     }
 }
 
@@ -80,3 +183,84 @@ impl TryInto<String> for &Config {
         Ok(toml::to_string(self)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(contents: &str) -> String {
+        match Config::parse(PathBuf::from("arfima.toml"), contents) {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_sets_path() {
+        let config =
+            Config::parse(PathBuf::from("arfima.toml"), "[number]\nactive = true\n").unwrap();
+
+        assert!(config.number().active());
+        assert_eq!(config.path(), Path::new("arfima.toml"));
+    }
+
+    #[test]
+    fn test_unknown_key_has_location_and_suggestion() {
+        let error = parse_error("[number]\nactive = true\nrelativ = true\n");
+
+        assert!(error.starts_with("arfima.toml:3:1: unknown field `relativ`"));
+        assert!(error.ends_with("did you mean `relative`?"));
+    }
+
+    #[test]
+    fn test_type_error_has_location() {
+        let error = parse_error("[history]\nsize = \"big\"\n");
+
+        assert!(error.starts_with("arfima.toml:2:8: "), "{error}");
+    }
+
+    #[test]
+    fn test_unknown_action_has_suggestion() {
+        let error = parse_error("[keys.normal]\nx = \"remvoe\"\n");
+
+        assert!(error.starts_with("arfima.toml:2:5: "), "{error}");
+        assert!(error.ends_with("did you mean `remove`?"), "{error}");
+    }
+
+    #[test]
+    fn test_default_config_matches_default() {
+        let config = Config::parse(PathBuf::from("arfima.toml"), DEFAULT_CONFIG).unwrap();
+
+        let written: String = (&config).try_into().unwrap();
+        let default: String = (&Config::default()).try_into().unwrap();
+
+        assert_eq!(written, default);
+    }
+
+    #[test]
+    fn test_write_default_and_save_create_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new").join("arfima.toml");
+
+        let mut config = Config::write_default(path.clone()).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().starts_with("# Arfima"));
+
+        config.mut_number().set_active(true);
+        config.save().unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(saved.starts_with("# Arfima"));
+        assert!(saved.contains("# Show line numbers next to the entries"));
+        assert!(Config::try_from(path.clone()).unwrap().number().active());
+
+        fs::remove_dir_all(dir.path().join("new")).unwrap();
+        config.save().unwrap();
+
+        assert!(Config::try_from(path).unwrap().number().active());
+    }
+
+    #[test]
+    fn test_save_without_path_fails() {
+        assert!(matches!(Config::default().save(), Err(Error::NoPath)));
+    }
+}
@@ -0,0 +1,243 @@
+use std::path::Path;
+
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    Pattern::new(pattern)
+        .map(|pattern| pattern.matches(value))
+        .unwrap_or(false)
+}
+
+/// A rule mapping files to a command, every matcher that is set has to match
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenerRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extensions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    glob: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mime: Option<String>,
+    /// `{}` is replaced with the path, without it the path is appended to the command
+    command: String,
+    /// Whether the command takes over the terminal or runs detached in the background
+    #[serde(default)]
+    foreground: bool,
+}
+
+impl OpenerRule {
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.command)
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    pub fn foreground(&self) -> bool {
+        self.foreground
+    }
+
+    /// `mime_type` is only called when the rule matches on MIME type
+    pub fn matches(&self, path: &Path, mime_type: &mut impl FnMut() -> Option<String>) -> bool {
+        if self.extensions.is_empty() && self.glob.is_none() && self.mime.is_none() {
+            return false;
+        }
+
+        if !self.extensions.is_empty() {
+            let Some(extension) = path.extension().map(|e| e.to_string_lossy()) else {
+                return false;
+            };
+
+            if !self
+                .extensions
+                .iter()
+                .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(&extension))
+            {
+                return false;
+            }
+        }
+
+        if let Some(glob) = &self.glob {
+            let matched = if glob.contains('/') {
+                matches_pattern(glob, path.to_string_lossy().as_ref())
+            } else {
+                path.file_name()
+                    .is_some_and(|name| matches_pattern(glob, name.to_string_lossy().as_ref()))
+            };
+
+            if !matched {
+                return false;
+            }
+        }
+
+        if let Some(mime) = &self.mime {
+            match mime_type() {
+                Some(mime_type) if matches_pattern(mime, &mime_type) => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenerConfig {
+    /// Open text files in the terminal editor instead of the platform opener
+    #[serde(default)]
+    text_in_editor: bool,
+    /// Program used for editing in the terminal instead of `$VISUAL`, `$EDITOR` or `$PAGER`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    editor: String,
+    /// Consulted in order before the platform opener
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<OpenerRule>,
+}
+
+impl OpenerConfig {
+    pub fn text_in_editor(&self) -> bool {
+        self.text_in_editor
+    }
+
+    pub fn set_text_in_editor(&mut self, text_in_editor: bool) {
+        self.text_in_editor = text_in_editor;
+    }
+
+    pub fn editor(&self) -> Option<&str> {
+        Some(self.editor.as_str()).filter(|editor| !editor.trim().is_empty())
+    }
+
+    pub fn set_editor(&mut self, editor: String) {
+        self.editor = editor;
+    }
+
+    pub fn rules(&self) -> &Vec<OpenerRule> {
+        &self.rules
+    }
+
+    pub fn mut_rules(&mut self) -> &mut Vec<OpenerRule> {
+        &mut self.rules
+    }
+
+    /// All the rules matching the path in order, `mime_type` is called at most once
+    pub fn matching_rules(
+        &self,
+        path: &Path,
+        mime_type: impl FnOnce() -> Option<String>,
+    ) -> Vec<&OpenerRule> {
+        let mut mime_type = Some(mime_type);
+        let mut cached = None;
+        let mut get_mime_type = || {
+            if let Some(mime_type) = mime_type.take() {
+                cached = mime_type();
+            }
+
+            cached.clone()
+        };
+
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(path, &mut get_mime_type))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(toml: &str) -> OpenerRule {
+        toml::from_str(toml).unwrap()
+    }
+
+    fn no_mime() -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_extension() {
+        let rule = rule("extensions = [\"md\", \".TXT\"]\ncommand = \"nvim\"");
+
+        assert!(rule.matches(Path::new("/a/readme.md"), &mut no_mime));
+        assert!(rule.matches(Path::new("/a/notes.txt"), &mut no_mime));
+        assert!(!rule.matches(Path::new("/a/main.rs"), &mut no_mime));
+        assert!(!rule.matches(Path::new("/a/Makefile"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_glob_on_file_name() {
+        let rule = rule("glob = \"*.log.[0-9]\"\ncommand = \"less\"");
+
+        assert!(rule.matches(Path::new("/var/log/app.log.1"), &mut no_mime));
+        assert!(!rule.matches(Path::new("/var/log/app.log"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_glob_on_path() {
+        let rule = rule("glob = \"/tmp/**/*.png\"\ncommand = \"feh\"");
+
+        assert!(rule.matches(Path::new("/tmp/a/b/c.png"), &mut no_mime));
+        assert!(!rule.matches(Path::new("/home/c.png"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_mime() {
+        let rule = rule("mime = \"image/*\"\ncommand = \"feh\"");
+
+        assert!(rule.matches(Path::new("/a/b"), &mut || Some("image/png".into())));
+        assert!(!rule.matches(Path::new("/a/b"), &mut || Some("text/plain".into())));
+        assert!(!rule.matches(Path::new("/a/b"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_all_matchers_need_to_match() {
+        let rule = rule("extensions = [\"png\"]\nglob = \"shot-*\"\ncommand = \"feh\"");
+
+        assert!(rule.matches(Path::new("/a/shot-1.png"), &mut no_mime));
+        assert!(!rule.matches(Path::new("/a/photo.png"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_rule_without_matchers_never_matches() {
+        let rule = rule("command = \"feh\"");
+
+        assert!(!rule.matches(Path::new("/a/b.png"), &mut no_mime));
+    }
+
+    #[test]
+    fn test_matching_rules_keeps_order_and_asks_mime_once() {
+        let config: OpenerConfig = toml::from_str(
+            r#"
+            [[rules]]
+            mime = "text/*"
+            command = "less"
+
+            [[rules]]
+            extensions = ["rs"]
+            command = "nvim"
+
+            [[rules]]
+            mime = "text/x-rust"
+            command = "code"
+            "#,
+        )
+        .unwrap();
+
+        let mut calls = 0;
+        let rules = config.matching_rules(Path::new("main.rs"), || {
+            calls += 1;
+            Some("text/x-rust".into())
+        });
+
+        assert_eq!(
+            rules.iter().map(|rule| rule.command()).collect::<Vec<_>>(),
+            vec!["less", "nvim", "code"]
+        );
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,357 @@
+use std::{fmt::Display, sync::LazyLock};
+
+use crate::utils::file::expand_tilde;
+
+use super::{Config, Error, Result, SizeUnits};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptionKind {
+    Bool,
+    Int,
+    String,
+    /// One of the given names
+    Enum(&'static [&'static str]),
+    /// Comma separated when written on the command line
+    List,
+}
+
+impl OptionKind {
+    pub fn parse(&self, option: &ConfigOption, raw: &str) -> Result<OptionValue> {
+        let invalid = || Error::InvalidOptionValue {
+            option: option.name(),
+            value: raw.to_string(),
+            expected: self.to_string(),
+        };
+
+        Ok(match self {
+            Self::Bool => match raw {
+                "true" | "on" | "yes" | "1" => OptionValue::Bool(true),
+                "false" | "off" | "no" | "0" => OptionValue::Bool(false),
+                _ => return Err(invalid()),
+            },
+            Self::Int => OptionValue::Int(raw.parse().map_err(|_| invalid())?),
+            Self::String => OptionValue::String(raw.to_string()),
+            Self::Enum(names) => OptionValue::Enum(
+                names
+                    .iter()
+                    .find(|name| **name == raw)
+                    .ok_or_else(invalid)?,
+            ),
+            Self::List => OptionValue::List(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            ),
+        })
+    }
+}
+
+impl Display for OptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "a boolean"),
+            Self::Int => write!(f, "a non-negative number"),
+            Self::String => write!(f, "a string"),
+            Self::Enum(names) => write!(f, "one of {}", names.join(", ")),
+            Self::List => write!(f, "a comma separated list"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptionValue {
+    Bool(bool),
+    Int(usize),
+    String(String),
+    Enum(&'static str),
+    List(Vec<String>),
+}
+
+impl OptionValue {
+    /// `+=`, adds to a number, appends to a string and pushes missing items to a list
+    pub fn add(self, other: OptionValue) -> Option<OptionValue> {
+        match (self, other) {
+            (Self::Int(value), Self::Int(other)) => Some(Self::Int(value.saturating_add(other))),
+            (Self::String(value), Self::String(other)) => Some(Self::String(value + &other)),
+            (Self::List(mut items), Self::List(other)) => {
+                for item in other {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Some(Self::List(items))
+            }
+            _ => None,
+        }
+    }
+
+    /// `-=`, subtracts from a number and removes items from a list
+    pub fn remove(self, other: OptionValue) -> Option<OptionValue> {
+        match (self, other) {
+            (Self::Int(value), Self::Int(other)) => Some(Self::Int(value.saturating_sub(other))),
+            (Self::List(mut items), Self::List(other)) => {
+                items.retain(|item| !other.contains(item));
+                Some(Self::List(items))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An option reachable through `:set`, backed by a `Config` field
+pub struct ConfigOption {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    kind: OptionKind,
+    get: fn(&Config) -> OptionValue,
+    set: fn(&mut Config, OptionValue),
+}
+
+impl ConfigOption {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn kind(&self) -> OptionKind {
+        self.kind
+    }
+
+    pub fn get(&self, config: &Config) -> OptionValue {
+        (self.get)(config)
+    }
+
+    /// The value is expected to be of the option kind, anything else is ignored
+    pub fn set(&self, config: &mut Config, value: OptionValue) {
+        (self.set)(config, value)
+    }
+
+    pub fn default_value(&self) -> OptionValue {
+        (self.get)(&Config::default())
+    }
+
+    /// Formats the value the way `:set option?` shows it
+    pub fn format(&self, value: &OptionValue) -> String {
+        match value {
+            OptionValue::Bool(true) => format!("  {}", self.name),
+            OptionValue::Bool(false) => format!("no{}", self.name),
+            OptionValue::Int(value) => format!("  {}={value}", self.name),
+            OptionValue::String(value) => format!("  {}={value}", self.name),
+            OptionValue::Enum(value) => format!("  {}={value}", self.name),
+            OptionValue::List(items) => format!("  {}={}", self.name, items.join(",")),
+        }
+    }
+}
+
+static OPTIONS: LazyLock<[ConfigOption; 9]> = LazyLock::new(|| {
+    [
+        ConfigOption {
+            name: "number",
+            aliases: &["nu", "number.active"],
+            kind: OptionKind::Bool,
+            get: |config| OptionValue::Bool(config.number().active()),
+            set: |config, value| {
+                if let OptionValue::Bool(value) = value {
+                    config.mut_number().set_active(value);
+                }
+            },
+        },
+        ConfigOption {
+            name: "relativenumber",
+            aliases: &["rnu", "number.relative"],
+            kind: OptionKind::Bool,
+            get: |config| OptionValue::Bool(config.number().relative()),
+            set: |config, value| {
+                if let OptionValue::Bool(value) = value {
+                    config.mut_number().set_relative(value);
+
+                    // relative numbers are only shown with numbers on
+                    if value {
+                        config.mut_number().set_active(true);
+                    }
+                }
+            },
+        },
+        ConfigOption {
+            name: "size_units",
+            aliases: &["display.size_units"],
+            kind: OptionKind::Enum(SizeUnits::NAMES),
+            get: |config| OptionValue::Enum(config.display().size_units().name()),
+            set: |config, value| {
+                if let OptionValue::Enum(name) = value
+                    && let Some(units) = SizeUnits::from_name(name)
+                {
+                    config.mut_display().set_size_units(units);
+                }
+            },
+        },
+        ConfigOption {
+            name: "history_size",
+            aliases: &["hi", "history", "history.size"],
+            kind: OptionKind::Int,
+            get: |config| OptionValue::Int(config.history().size()),
+            set: |config, value| {
+                if let OptionValue::Int(value) = value {
+                    config.mut_history().set_size(value);
+                }
+            },
+        },
+        ConfigOption {
+            name: "user_dirs",
+            aliases: &["common_entries.user_dirs"],
+            kind: OptionKind::Bool,
+            get: |config| OptionValue::Bool(config.common_entries().user_dirs()),
+            set: |config, value| {
+                if let OptionValue::Bool(value) = value {
+                    config.mut_common_entries().set_user_dirs(value);
+                }
+            },
+        },
+        ConfigOption {
+            name: "other_paths",
+            aliases: &["common_entries.other_paths"],
+            kind: OptionKind::List,
+            get: |config| {
+                OptionValue::List(
+                    config
+                        .common_entries()
+                        .other_paths()
+                        .iter()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .collect(),
+                )
+            },
+            set: |config, value| {
+                if let OptionValue::List(items) = value {
+                    config.mut_common_entries().set_other_paths(
+                        items
+                            .iter()
+                            .map(|item| expand_tilde(item))
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            },
+        },
+        ConfigOption {
+            name: "text_in_editor",
+            aliases: &["opener.text_in_editor"],
+            kind: OptionKind::Bool,
+            get: |config| OptionValue::Bool(config.opener().text_in_editor()),
+            set: |config, value| {
+                if let OptionValue::Bool(value) = value {
+                    config.mut_opener().set_text_in_editor(value);
+                }
+            },
+        },
+        ConfigOption {
+            name: "editor",
+            aliases: &["opener.editor"],
+            kind: OptionKind::String,
+            get: |config| {
+                OptionValue::String(config.opener().editor().unwrap_or_default().to_string())
+            },
+            set: |config, value| {
+                if let OptionValue::String(value) = value {
+                    config.mut_opener().set_editor(value);
+                }
+            },
+        },
+        ConfigOption {
+            name: "restore_session",
+            aliases: &["session.restore"],
+            kind: OptionKind::Bool,
+            get: |config| OptionValue::Bool(config.session().restore()),
+            set: |config, value| {
+                if let OptionValue::Bool(value) = value {
+                    config.mut_session().set_restore(value);
+                }
+            },
+        },
+    ]
+});
+
+pub fn find_option(name: &str) -> Option<&'static ConfigOption> {
+    OPTIONS
+        .iter()
+        .find(|option| option.name == name || option.aliases.contains(&name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_option_by_alias() {
+        assert_eq!(
+            find_option("rnu").map(ConfigOption::name),
+            Some("relativenumber")
+        );
+        assert_eq!(
+            find_option("history.size").map(ConfigOption::name),
+            Some("history_size")
+        );
+        assert!(find_option("bogus").is_none());
+    }
+
+    #[test]
+    fn test_set_get_and_default() {
+        let mut config = Config::default();
+        let option = find_option("history_size").unwrap();
+
+        option.set(&mut config, option.kind().parse(option, "12").unwrap());
+
+        assert_eq!(option.get(&config), OptionValue::Int(12));
+        assert_eq!(option.default_value(), OptionValue::Int(50));
+        assert_eq!(option.format(&OptionValue::Int(12)), "  history_size=12");
+    }
+
+    #[test]
+    fn test_parse_errors_report_expected_type() {
+        let option = find_option("number").unwrap();
+        let error = OptionKind::Int.parse(option, "many").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid value 'many' for number, expected a non-negative number"
+        );
+
+        let error = OptionKind::Bool.parse(option, "maybe").unwrap_err();
+
+        assert!(error.to_string().ends_with("expected a boolean"));
+    }
+
+    #[test]
+    fn test_enum_accepts_only_its_names() {
+        let mut config = Config::default();
+        let option = find_option("size_units").unwrap();
+        let error = option.kind().parse(option, "octal").unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .ends_with("expected one of binary, decimal")
+        );
+
+        option.set(&mut config, option.kind().parse(option, "decimal").unwrap());
+
+        assert_eq!(config.display().size_units(), SizeUnits::Decimal);
+        assert_eq!(option.default_value(), OptionValue::Enum("binary"));
+    }
+
+    #[test]
+    fn test_add_and_remove() {
+        let list = OptionValue::List(vec!["a".into(), "b".into()]);
+
+        assert_eq!(
+            list.clone()
+                .add(OptionValue::List(vec!["b".into(), "c".into()])),
+            Some(OptionValue::List(vec!["a".into(), "b".into(), "c".into()]))
+        );
+        assert_eq!(
+            list.remove(OptionValue::List(vec!["a".into()])),
+            Some(OptionValue::List(vec!["b".into()]))
+        );
+        assert_eq!(OptionValue::Bool(true).add(OptionValue::Bool(true)), None);
+    }
+}
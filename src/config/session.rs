@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionConfig {
+    /// Restores the windows of the last run when started without a directory
+    #[serde(default)]
+    restore: bool,
+}
+
+impl SessionConfig {
+    pub fn restore(&self) -> bool {
+        self.restore
+    }
+
+    pub fn set_restore(&mut self, restore: bool) {
+        self.restore = restore;
+    }
+}
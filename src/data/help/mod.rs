@@ -1,6 +1,6 @@
 mod adding_bindings;
 mod help_bindings;
-mod normal_bindings;
+mod opening_bindings;
 mod removing_bindings;
 mod renaming_bindings;
 mod types;
@@ -9,15 +9,13 @@ use std::sync::LazyLock;
 
 pub use types::{KeyBinding, ModeKeyBindings};
 
-use crate::app::InputMode;
+use crate::{app::InputMode, keymap::Keymap};
+
+/// Normal mode is not part of [`HELP`], its bindings are generated from the keymap
+pub const NORMAL_MODE_DESCRIPTION: &str = "Normal mode: navigate and interact";
 
 static HELP: LazyLock<[ModeKeyBindings; 5]> = LazyLock::new(|| {
     [
-        ModeKeyBindings::new(
-            InputMode::default(),
-            "Normal mode: navigate and interact",
-            &*normal_bindings::NORMAL_BINDINGS,
-        ),
         ModeKeyBindings::new(
             InputMode::adding_default(),
             "Adding mode: type a new name",
@@ -33,6 +31,11 @@ static HELP: LazyLock<[ModeKeyBindings; 5]> = LazyLock::new(|| {
             "Removing mode: confirm deletion",
             &*removing_bindings::REMOVING_BINDINGS,
         ),
+        ModeKeyBindings::new(
+            InputMode::opening_default(),
+            "Opening mode: choose an application",
+            &*opening_bindings::OPENING_BINDINGS,
+        ),
         ModeKeyBindings::new(
             InputMode::help_default(),
             "Help mode: read keybindings",
@@ -48,6 +51,6 @@ pub fn get_help() -> &'static [ModeKeyBindings<'static>] {
     &*HELP
 }
 
-pub fn get_help_entries_len() -> usize {
-    *HELP_ENTRIES_LEN
+pub fn get_help_entries_len(keymap: &Keymap) -> usize {
+    *HELP_ENTRIES_LEN + keymap.bound_actions().count() + keymap.key_mappings().count() + 1
 }
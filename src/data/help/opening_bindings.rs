@@ -0,0 +1,41 @@
+use std::sync::LazyLock;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use super::KeyBinding;
+
+pub static OPENING_BINDINGS: LazyLock<[KeyBinding; 5]> = LazyLock::new(|| {
+    [
+        KeyBinding::new(
+            "open",
+            &[(KeyModifiers::NONE, KeyCode::Enter)],
+            "Open with the selected application",
+        ),
+        KeyBinding::new(
+            "always open",
+            &[(KeyModifiers::NONE, KeyCode::Char('a'))],
+            "Open with the selected application and use it for this file type from now on",
+        ),
+        KeyBinding::new(
+            "navigate",
+            &[
+                (KeyModifiers::NONE, KeyCode::Char('j')),
+                (KeyModifiers::NONE, KeyCode::Char('k')),
+            ],
+            "Move selection down or up",
+        ),
+        KeyBinding::new(
+            "cancel",
+            &[(KeyModifiers::NONE, KeyCode::Esc)],
+            "Cancel opening",
+        ),
+        KeyBinding::new(
+            "quit",
+            &[
+                (KeyModifiers::CONTROL, KeyCode::Char('c')),
+                (KeyModifiers::CONTROL, KeyCode::Char('C')),
+            ],
+            "Quit the application",
+        ),
+    ]
+});
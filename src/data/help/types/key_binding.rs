@@ -1,12 +1,9 @@
 use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::app::Precommand;
-
 pub struct KeyBinding<'a> {
     name: &'a str,
     keys: &'a [(KeyModifiers, KeyCode)],
     description: &'a str,
-    precommand: Option<Precommand>,
 }
 
 impl<'a> KeyBinding<'a> {
@@ -15,21 +12,6 @@ impl<'a> KeyBinding<'a> {
             name,
             keys,
             description,
-            precommand: None,
-        }
-    }
-
-    pub fn with_precommand(
-        name: &'a str,
-        keys: &'a [(KeyModifiers, KeyCode)],
-        description: &'a str,
-        precommand: Precommand,
-    ) -> Self {
-        Self {
-            name,
-            keys,
-            description,
-            precommand: Some(precommand),
         }
     }
 
@@ -44,8 +26,4 @@ impl<'a> KeyBinding<'a> {
     pub fn description(&self) -> &str {
         self.description
     }
-
-    pub fn precommand(&self) -> Option<&Precommand> {
-        self.precommand.as_ref()
-    }
 }
@@ -25,7 +25,7 @@ impl<'a> ModeKeyBindings<'a> {
         self.description
     }
 
-    pub fn items(&self) -> &[KeyBinding] {
+    pub fn items(&self) -> &[KeyBinding<'_>] {
         self.items
     }
 }
@@ -0,0 +1,68 @@
+use crate::keymap::Action;
+
+/// Default normal mode bindings, user bindings from `[keys.normal]` are applied on top
+pub const NORMAL_KEYS: [(&str, Action); 63] = [
+    ("q", Action::Quit),
+    ("<C-c>", Action::QuitAll),
+    ("Q", Action::QuitChoosingDirectory),
+    ("<Esc>", Action::Reset),
+    ("<C-h>", Action::Help),
+    (":", Action::CommandLine),
+    ("j", Action::MoveDown),
+    ("<Down>", Action::MoveDown),
+    ("k", Action::MoveUp),
+    ("<Up>", Action::MoveUp),
+    ("h", Action::GoParent),
+    ("<Left>", Action::GoParent),
+    ("<BS>", Action::GoParent),
+    ("l", Action::Open),
+    ("<Right>", Action::Open),
+    ("<CR>", Action::Open),
+    ("o", Action::OpenWith),
+    ("e", Action::Edit),
+    ("a", Action::Add),
+    ("r", Action::Rename),
+    ("d", Action::Remove),
+    ("m", Action::Mark),
+    ("x", Action::Extract),
+    ("gg", Action::JumpToTop),
+    ("<Home>", Action::JumpToTop),
+    ("G", Action::JumpToBottom),
+    ("<End>", Action::JumpToBottom),
+    ("<C-w>w", Action::NextWindow),
+    ("<C-w><C-w>", Action::NextWindow),
+    ("<C-w>W", Action::PrevWindow),
+    ("<C-w>h", Action::FocusLeft),
+    ("<C-w><Left>", Action::FocusLeft),
+    ("<C-w>j", Action::FocusDown),
+    ("<C-w><Down>", Action::FocusDown),
+    ("<C-w>k", Action::FocusUp),
+    ("<C-w><Up>", Action::FocusUp),
+    ("<C-w>l", Action::FocusRight),
+    ("<C-w><Right>", Action::FocusRight),
+    ("<C-w>v", Action::SplitHorizontal),
+    ("<C-w>s", Action::SplitVertical),
+    ("<C-w>>", Action::IncreaseWidth),
+    ("<C-w><lt>", Action::DecreaseWidth),
+    ("<C-w>+", Action::IncreaseHeight),
+    ("<C-w>-", Action::DecreaseHeight),
+    ("<C-w>o", Action::OnlyWindow),
+    ("<C-w>=", Action::EqualizeWindows),
+    ("<C-w><Bar>", Action::MaximizeWidth),
+    ("<C-w>_", Action::MaximizeHeight),
+    ("<C-w>x", Action::SwapWindow),
+    ("<C-w>r", Action::RotateWindows),
+    ("<C-w>H", Action::MoveWindowFarLeft),
+    ("<C-w>J", Action::MoveWindowFarBottom),
+    ("<C-w>K", Action::MoveWindowFarTop),
+    ("<C-w>L", Action::MoveWindowFarRight),
+    ("<Space>n", Action::ToggleCommonEntries),
+    ("<Tab>", Action::SwitchPane),
+    ("<F4>", Action::Edit),
+    ("<F5>", Action::CopyToOtherPane),
+    ("<F6>", Action::MoveToOtherPane),
+    ("<F7>", Action::Add),
+    ("<F8>", Action::Remove),
+    ("<Ins>", Action::Mark),
+    ("<F10>", Action::QuitAll),
+];
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Used when the shared-mime-info database is not installed
+static MIME_TYPES: LazyLock<HashMap<&str, &str>> = LazyLock::new(|| {
+    HashMap::from([
+        ("7z", "application/x-7z-compressed"),
+        ("aac", "audio/aac"),
+        ("avi", "video/x-msvideo"),
+        ("avif", "image/avif"),
+        ("bash", "application/x-shellscript"),
+        ("bmp", "image/bmp"),
+        ("bz2", "application/x-bzip2"),
+        ("c", "text/x-csrc"),
+        ("cpp", "text/x-c++src"),
+        ("css", "text/css"),
+        ("csv", "text/csv"),
+        ("deb", "application/vnd.debian.binary-package"),
+        ("doc", "application/msword"),
+        (
+            "docx",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        ),
+        ("epub", "application/epub+zip"),
+        ("flac", "audio/flac"),
+        ("gif", "image/gif"),
+        ("go", "text/x-go"),
+        ("gz", "application/gzip"),
+        ("h", "text/x-chdr"),
+        ("hpp", "text/x-c++hdr"),
+        ("htm", "text/html"),
+        ("html", "text/html"),
+        ("ico", "image/vnd.microsoft.icon"),
+        ("iso", "application/x-cd-image"),
+        ("jar", "application/x-java-archive"),
+        ("java", "text/x-java"),
+        ("jpeg", "image/jpeg"),
+        ("jpg", "image/jpeg"),
+        ("js", "text/javascript"),
+        ("json", "application/json"),
+        ("log", "text/x-log"),
+        ("lua", "text/x-lua"),
+        ("m4a", "audio/mp4"),
+        ("md", "text/markdown"),
+        ("mkv", "video/x-matroska"),
+        ("mov", "video/quicktime"),
+        ("mp3", "audio/mpeg"),
+        ("mp4", "video/mp4"),
+        ("odp", "application/vnd.oasis.opendocument.presentation"),
+        ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+        ("odt", "application/vnd.oasis.opendocument.text"),
+        ("ogg", "audio/ogg"),
+        ("opus", "audio/ogg"),
+        ("otf", "font/otf"),
+        ("pdf", "application/pdf"),
+        ("php", "application/x-php"),
+        ("png", "image/png"),
+        ("ppt", "application/vnd.ms-powerpoint"),
+        (
+            "pptx",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        ),
+        ("ps", "application/postscript"),
+        ("py", "text/x-python"),
+        ("rar", "application/vnd.rar"),
+        ("rb", "application/x-ruby"),
+        ("rpm", "application/x-rpm"),
+        ("rs", "text/rust"),
+        ("rtf", "application/rtf"),
+        ("sh", "application/x-shellscript"),
+        ("sql", "application/sql"),
+        ("sqlite", "application/vnd.sqlite3"),
+        ("svg", "image/svg+xml"),
+        ("tar", "application/x-tar"),
+        ("tgz", "application/x-compressed-tar"),
+        ("tif", "image/tiff"),
+        ("tiff", "image/tiff"),
+        ("toml", "application/toml"),
+        ("ts", "text/x-typescript"),
+        ("ttf", "font/ttf"),
+        ("txt", "text/plain"),
+        ("wasm", "application/wasm"),
+        ("wav", "audio/x-wav"),
+        ("webm", "video/webm"),
+        ("webp", "image/webp"),
+        ("woff", "font/woff"),
+        ("woff2", "font/woff2"),
+        ("xls", "application/vnd.ms-excel"),
+        (
+            "xlsx",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        ),
+        ("xml", "application/xml"),
+        ("xz", "application/x-xz"),
+        ("yaml", "application/yaml"),
+        ("yml", "application/yaml"),
+        ("zip", "application/zip"),
+        ("zsh", "application/x-shellscript"),
+        ("zst", "application/zstd"),
+    ])
+});
+
+pub fn get_mime_types() -> &'static HashMap<&'static str, &'static str> {
+    &MIME_TYPES
+}
@@ -1,5 +1,19 @@
 pub mod help;
 pub mod icons;
+pub mod keys;
+pub mod mime_types;
 pub mod package;
 
 pub const CONFIG_FILE_NAME: &str = "arfima.toml";
+
+/// Next to the config file, executables in it are run as commands named after them
+pub const PLUGINS_DIRECTORY_NAME: &str = "plugins";
+
+/// Written on first start when there is no config file, parses to `Config::default()`
+pub const DEFAULT_CONFIG: &str = include_str!("default_config.toml");
+
+/// Printed by `--init` for bash and zsh, wraps arfima to change to the chosen directory
+pub const SHELL_INIT_POSIX: &str = include_str!("shell/init.sh");
+
+/// Printed by `--init fish`
+pub const SHELL_INIT_FISH: &str = include_str!("shell/init.fish");
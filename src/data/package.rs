@@ -1,3 +1,5 @@
+use directories::ProjectDirs;
+
 pub struct Package<'a> {
     tld: &'a str,
     domain: &'a str,
@@ -27,3 +29,7 @@ const PACKAGE: Package = Package {
 pub fn get() -> &'static Package<'static> {
     &PACKAGE
 }
+
+pub fn project_dirs() -> Option<ProjectDirs> {
+    ProjectDirs::from(PACKAGE.tld(), PACKAGE.domain(), PACKAGE.application())
+}
@@ -0,0 +1,12 @@
+# arfima changes the shell to the directory it quits in
+function arfima --wraps arfima
+    set -l tmp (mktemp -t arfima-choosedir.XXXXXX); or return
+    command arfima --choosedir $tmp $argv
+    set -l code $status
+    set -l dir (cat -- $tmp)
+    rm -f -- $tmp
+    if test -n "$dir"; and test "$dir" != "$PWD"; and test -d "$dir"
+        cd -- $dir
+    end
+    return $code
+end
@@ -0,0 +1,13 @@
+# arfima changes the shell to the directory it quits in
+arfima() {
+    local tmp dir code
+    tmp="$(mktemp -t arfima-choosedir.XXXXXX)" || return
+    command arfima --choosedir "$tmp" "$@"
+    code=$?
+    dir="$(cat -- "$tmp")"
+    rm -f -- "$tmp"
+    if [ -n "$dir" ] && [ "$dir" != "$PWD" ] && [ -d "$dir" ]; then
+        cd -- "$dir" || return
+    fi
+    return "$code"
+}
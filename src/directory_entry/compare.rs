@@ -0,0 +1,324 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::OsString,
+    fs::{self, File, Metadata},
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// How an entry of one directory compares to the entry of the same name in the other
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffStatus {
+    OnlyLeft,
+    OnlyRight,
+    Different,
+    Same,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EntryDiff {
+    status: DiffStatus,
+    /// The side modified last when both are files that differ
+    newer: Option<Side>,
+}
+
+/// The entries of two directories compared by name, size and modification time
+#[derive(Debug)]
+pub struct DirectoryDiff {
+    left: PathBuf,
+    right: PathBuf,
+    entries: BTreeMap<OsString, EntryDiff>,
+}
+
+/// One copy of a sync, replacing `destination` when it exists
+#[derive(Debug, PartialEq)]
+pub struct SyncCopy {
+    pub source: PathBuf,
+    pub destination: PathBuf,
+    pub replace: bool,
+}
+
+/// The entries of `directory` by name, following symlinks unless they are broken
+fn read_entries(directory: &Path) -> io::Result<HashMap<OsString, Metadata>> {
+    fs::read_dir(directory)?
+        .map(|entry| {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = fs::metadata(&path).or_else(|_| fs::symlink_metadata(&path))?;
+            Ok((entry.file_name(), metadata))
+        })
+        .collect()
+}
+
+/// Reads from `file` until `buffer` is full or the file ends, returning how much was read
+fn read_chunk(file: &mut File, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match file.read(&mut buffer[filled..])? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
+/// Compares two files chunk by chunk, stopping at the first difference
+fn same_content(left: &Path, right: &Path) -> io::Result<bool> {
+    let (mut left, mut right) = (File::open(left)?, File::open(right)?);
+    let mut left_buffer = vec![0; 64 * 1024];
+    let mut right_buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = read_chunk(&mut left, &mut left_buffer)?;
+        if read != read_chunk(&mut right, &mut right_buffer)?
+            || left_buffer[..read] != right_buffer[..read]
+        {
+            return Ok(false);
+        }
+        if read == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+fn modified(metadata: &Metadata) -> Option<SystemTime> {
+    metadata.modified().ok()
+}
+
+impl DirectoryDiff {
+    /// Compares the entries directly inside `left` and `right`, directories only by their names,
+    /// files also by size and modification time, or by content when `content` is set
+    pub fn compare(left: &Path, right: &Path, content: bool) -> io::Result<Self> {
+        let left_entries = read_entries(left)?;
+        let mut right_entries = read_entries(right)?;
+        let mut entries = BTreeMap::new();
+
+        for (name, left_metadata) in left_entries {
+            let Some(right_metadata) = right_entries.remove(&name) else {
+                entries.insert(
+                    name,
+                    EntryDiff {
+                        status: DiffStatus::OnlyLeft,
+                        newer: None,
+                    },
+                );
+                continue;
+            };
+
+            let diff = if left_metadata.is_dir() || right_metadata.is_dir() {
+                EntryDiff {
+                    status: if left_metadata.is_dir() == right_metadata.is_dir() {
+                        DiffStatus::Same
+                    } else {
+                        DiffStatus::Different
+                    },
+                    newer: None,
+                }
+            } else {
+                let same = if left_metadata.len() != right_metadata.len() {
+                    false
+                } else if content {
+                    same_content(&left.join(&name), &right.join(&name))?
+                } else {
+                    modified(&left_metadata) == modified(&right_metadata)
+                };
+
+                EntryDiff {
+                    status: if same {
+                        DiffStatus::Same
+                    } else {
+                        DiffStatus::Different
+                    },
+                    newer: (!same).then(|| {
+                        if modified(&left_metadata) >= modified(&right_metadata) {
+                            Side::Left
+                        } else {
+                            Side::Right
+                        }
+                    }),
+                }
+            };
+
+            entries.insert(name, diff);
+        }
+
+        for name in right_entries.into_keys() {
+            entries.insert(
+                name,
+                EntryDiff {
+                    status: DiffStatus::OnlyRight,
+                    newer: None,
+                },
+            );
+        }
+
+        Ok(Self {
+            left: left.to_owned(),
+            right: right.to_owned(),
+            entries,
+        })
+    }
+
+    pub fn directory(&self, side: Side) -> &Path {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    /// The status of every entry that exists on `side`, by its path there
+    pub fn statuses(&self, side: Side) -> HashMap<PathBuf, DiffStatus> {
+        let missing = match side {
+            Side::Left => DiffStatus::OnlyRight,
+            Side::Right => DiffStatus::OnlyLeft,
+        };
+
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.status != missing)
+            .map(|(name, entry)| (self.directory(side).join(name), entry.status))
+            .collect()
+    }
+
+    /// How many entries are only on the left, only on the right and different
+    pub fn counts(&self) -> (usize, usize, usize) {
+        let count = |status| {
+            self.entries
+                .values()
+                .filter(|entry| entry.status == status)
+                .count()
+        };
+
+        (
+            count(DiffStatus::OnlyLeft),
+            count(DiffStatus::OnlyRight),
+            count(DiffStatus::Different),
+        )
+    }
+
+    /// The copies that bring the entries missing on `to`, and the files newer on the other side,
+    /// over to `to`
+    pub fn sync_plan(&self, to: Side) -> Vec<SyncCopy> {
+        let from = to.other();
+        let only_from = match from {
+            Side::Left => DiffStatus::OnlyLeft,
+            Side::Right => DiffStatus::OnlyRight,
+        };
+
+        self.entries
+            .iter()
+            .filter(|(_, entry)| {
+                entry.status == only_from
+                    || (entry.status == DiffStatus::Different && entry.newer == Some(from))
+            })
+            .map(|(name, entry)| SyncCopy {
+                source: self.directory(from).join(name),
+                destination: self.directory(to).join(name),
+                replace: entry.status == DiffStatus::Different,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn write(path: &Path, contents: &str, modified: SystemTime) {
+        fs::write(path, contents).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_compare_and_plan() {
+        let root = tempfile::tempdir().unwrap();
+        let (left, right) = (root.path().join("left"), root.path().join("right"));
+        fs::create_dir_all(left.join("shared")).unwrap();
+        fs::create_dir_all(right.join("shared")).unwrap();
+
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let new = old + Duration::from_secs(60);
+        write(&left.join("only"), "a", old);
+        write(&right.join("theirs"), "b", old);
+        write(&left.join("same"), "c", old);
+        write(&right.join("same"), "c", old);
+        write(&left.join("touched"), "d", new);
+        write(&right.join("touched"), "d", old);
+        write(&left.join("edited"), "e", old);
+        write(&right.join("edited"), "ee", new);
+
+        let diff = DirectoryDiff::compare(&left, &right, false).unwrap();
+
+        assert_eq!(diff.counts(), (1, 1, 2));
+        assert_eq!(
+            diff.statuses(Side::Left).get(&left.join("touched")),
+            Some(&DiffStatus::Different)
+        );
+        assert!(!diff.statuses(Side::Left).contains_key(&left.join("theirs")));
+        assert_eq!(
+            diff.sync_plan(Side::Right),
+            vec![
+                SyncCopy {
+                    source: left.join("only"),
+                    destination: right.join("only"),
+                    replace: false,
+                },
+                SyncCopy {
+                    source: left.join("touched"),
+                    destination: right.join("touched"),
+                    replace: true,
+                },
+            ]
+        );
+
+        // equal contents only differing in time are the same when comparing contents
+        let diff = DirectoryDiff::compare(&left, &right, true).unwrap();
+        assert_eq!(diff.counts(), (1, 1, 1));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_and_contents() {
+        let root = tempfile::tempdir().unwrap();
+        let (left, right) = (root.path().join("left"), root.path().join("right"));
+        fs::create_dir_all(left.join("linked")).unwrap();
+        fs::create_dir_all(&right).unwrap();
+        std::os::unix::fs::symlink(left.join("linked"), right.join("linked")).unwrap();
+
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let contents = "x".repeat(100 * 1024);
+        let mut changed = contents.clone();
+        changed.replace_range(90 * 1024..90 * 1024 + 1, "y");
+        write(&left.join("big"), &contents, modified);
+        write(&right.join("big"), &changed, modified);
+
+        let diff = DirectoryDiff::compare(&left, &right, true).unwrap();
+        let statuses = diff.statuses(Side::Right);
+
+        assert_eq!(statuses[&right.join("linked")], DiffStatus::Same);
+        assert_eq!(statuses[&right.join("big")], DiffStatus::Different);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Display, io};
+use std::{fmt::Display, io, path::PathBuf};
 
 #[derive(Debug)]
 #[allow(clippy::enum_variant_names)]
@@ -7,6 +7,11 @@ pub enum Error {
     MissingPath,
     MissingEntryType,
     Io(io::Error),
+    Archive(String),
+    NotInArchive(PathBuf),
+    ReadOnly(PathBuf),
+    UnsupportedArchive(PathBuf),
+    AlreadyExists(PathBuf),
 }
 
 impl Display for Error {
@@ -16,6 +21,17 @@ impl Display for Error {
             Self::MissingPath => write!(f, "path is missing"),
             Self::MissingEntryType => write!(f, "entry_type is missing"),
             Self::Io(e) => write!(f, "IO error: {e}"),
+            Self::Archive(e) => write!(f, "Archive error: {e}"),
+            Self::NotInArchive(path) => write!(f, "Not inside an archive: {}", path.display()),
+            Self::UnsupportedArchive(path) => write!(
+                f,
+                "Not a .zip, .tar, .tar.gz or .tar.zst archive: {}",
+                path.display()
+            ),
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::ReadOnly(path) => {
+                write!(f, "Archives are read-only, can't change {}", path.display())
+            }
         }
     }
 }
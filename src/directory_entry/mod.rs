@@ -1,18 +1,21 @@
+mod compare;
 mod directory_entry_builder;
 mod directory_entry_type;
 mod error;
 mod read_directory;
+pub mod vfs;
 
 use std::{path::PathBuf, time::SystemTime};
 
 use chrono::{DateTime, Local};
+pub use compare::{DiffStatus, DirectoryDiff, Side, SyncCopy};
 pub use directory_entry_builder::DirectoryEntryBuilder;
 pub use directory_entry_type::DirectoryEntryType;
 pub use error::Error;
 use ratatui::style::Color;
 pub use read_directory::read_directory;
 
-use crate::{types::NaturalString, utils::get_icon_and_color};
+use crate::{config::SizeUnits, types::NaturalString, utils::get_icon_and_color};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -75,15 +78,16 @@ impl DirectoryEntry {
         }
     }
 
-    pub fn formatted_size(&self) -> Option<String> {
+    pub fn formatted_size(&self, units: SizeUnits) -> Option<String> {
         match &self.entry_type {
             DirectoryEntryType::File { extension: _, size } => {
                 const UNITS: [&str; 5] = ["B", "kB", "MB", "GB", "TB"];
+                let base = units.base();
                 let mut size = *size as f64;
                 let mut unit = 0;
 
-                while size >= 1024.0 && unit < UNITS.len() - 1 {
-                    size /= 1024.0;
+                while size >= base && unit < UNITS.len() - 1 {
+                    size /= base;
                     unit += 1;
                 }
 
@@ -1,8 +1,13 @@
 use std::{fs, path::Path};
 
-use super::{DirectoryEntry, Result};
+use super::{DirectoryEntry, Result, vfs::Location};
 
+/// Lists the entries of `path`, which may also be an archive or a directory inside one
 pub fn read_directory<P: AsRef<Path>>(path: P) -> Result<Vec<DirectoryEntry>> {
+    Location::resolve(path.as_ref()).read_directory()
+}
+
+pub(super) fn read_local_directory(path: &Path) -> Result<Vec<DirectoryEntry>> {
     let mut entries = fs::read_dir(path)?
         .filter_map(|entry| (&entry.ok()?.path()).try_into().ok())
         .collect::<Vec<_>>();
@@ -0,0 +1,383 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use chrono::{Local, NaiveDate, TimeZone};
+use flate2::read::GzDecoder;
+use zip::ZipArchive;
+
+use crate::directory_entry::{DirectoryEntry, DirectoryEntryType, Error, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+    TarZst,
+}
+
+/// Extensions of each kind, the longer ones first so `.tar.gz` is not taken for `.gz`
+const EXTENSIONS: [(&str, ArchiveKind); 6] = [
+    (".tar.gz", ArchiveKind::TarGz),
+    (".tar.zst", ArchiveKind::TarZst),
+    (".tgz", ArchiveKind::TarGz),
+    (".tzst", ArchiveKind::TarZst),
+    (".tar", ArchiveKind::Tar),
+    (".zip", ArchiveKind::Zip),
+];
+
+impl ArchiveKind {
+    /// Tells the kind of archive by the extension of `path`
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+
+        EXTENSIONS
+            .iter()
+            .find(|(extension, _)| name.ends_with(extension))
+            .map(|(_, kind)| *kind)
+    }
+
+    /// The name of the archive at `path` without its archive extension
+    pub fn stem(path: &Path) -> Option<String> {
+        let name = path.file_name()?.to_string_lossy().to_string();
+        let lowercase = name.to_lowercase();
+
+        EXTENSIONS
+            .iter()
+            .find(|(extension, _)| lowercase.ends_with(extension))
+            .map(|(extension, _)| name[..name.len() - extension.len()].to_string())
+            .filter(|stem| !stem.is_empty())
+    }
+}
+
+/// A file or directory stored in an archive
+#[derive(Debug)]
+struct Member {
+    /// Relative to the root of the archive, only made of normal components
+    path: PathBuf,
+    is_dir: bool,
+    size: u64,
+    modified: Option<SystemTime>,
+}
+
+/// The path inside an archive, dropping `.`, `..` and roots so nothing points outside of it
+fn member_path(name: &Path) -> PathBuf {
+    name.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect()
+}
+
+fn zip_modified(date: zip::DateTime) -> Option<SystemTime> {
+    let naive =
+        NaiveDate::from_ymd_opt(date.year().into(), date.month().into(), date.day().into())?
+            .and_hms_opt(
+                date.hour().into(),
+                date.minute().into(),
+                date.second().into(),
+            )?;
+
+    Local
+        .from_local_datetime(&naive)
+        .single()
+        .map(SystemTime::from)
+}
+
+fn open_zip(archive: &Path) -> Result<ZipArchive<File>> {
+    ZipArchive::new(File::open(archive)?).map_err(|e| Error::Archive(e.to_string()))
+}
+
+fn open_tar(archive: &Path, kind: ArchiveKind) -> Result<tar::Archive<Box<dyn Read>>> {
+    let file = File::open(archive)?;
+    let reader: Box<dyn Read> = match kind {
+        ArchiveKind::TarGz => Box::new(GzDecoder::new(file)),
+        ArchiveKind::TarZst => Box::new(zstd::Decoder::new(file)?),
+        _ => Box::new(file),
+    };
+
+    Ok(tar::Archive::new(reader))
+}
+
+fn read_members(archive: &Path, kind: ArchiveKind) -> Result<Vec<Member>> {
+    let mut members = Vec::new();
+
+    match kind {
+        ArchiveKind::Zip => {
+            let mut zip = open_zip(archive)?;
+
+            for index in 0..zip.len() {
+                let file = zip
+                    .by_index(index)
+                    .map_err(|e| Error::Archive(e.to_string()))?;
+
+                members.push(Member {
+                    path: member_path(Path::new(file.name())),
+                    is_dir: file.is_dir(),
+                    size: file.size(),
+                    modified: file.last_modified().and_then(zip_modified),
+                });
+            }
+        }
+        ArchiveKind::Tar | ArchiveKind::TarGz | ArchiveKind::TarZst => {
+            for entry in open_tar(archive, kind)?.entries()? {
+                let entry = entry?;
+                let header = entry.header();
+
+                members.push(Member {
+                    path: member_path(&entry.path()?),
+                    is_dir: header.entry_type().is_dir(),
+                    size: header.size()?,
+                    modified: header
+                        .mtime()
+                        .ok()
+                        .map(|seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)),
+                });
+            }
+        }
+    }
+
+    members.retain(|member| member.path.components().next().is_some());
+
+    Ok(members)
+}
+
+/// The names of the entries at the root of the archive
+pub fn top_level_names(archive: &Path, kind: ArchiveKind) -> Result<BTreeSet<OsString>> {
+    Ok(read_members(archive, kind)?
+        .into_iter()
+        .filter_map(|member| {
+            member
+                .path
+                .components()
+                .next()
+                .map(|component| component.as_os_str().to_owned())
+        })
+        .collect())
+}
+
+/// Whether `inner` is the root of the archive or one of the directories in it
+pub fn is_archive_directory(archive: &Path, kind: ArchiveKind, inner: &Path) -> bool {
+    if inner.components().next().is_none() {
+        return true;
+    }
+
+    read_members(archive, kind).is_ok_and(|members| {
+        members
+            .iter()
+            .any(|member| member.path.starts_with(inner) && (member.path != inner || member.is_dir))
+    })
+}
+
+/// Lists the entries directly inside `inner`, with their paths under `archive`, directories that
+/// only appear as parents of other members included
+pub fn read_archive_directory(
+    archive: &Path,
+    kind: ArchiveKind,
+    inner: &Path,
+) -> Result<Vec<DirectoryEntry>> {
+    let mut children: BTreeMap<String, Option<&Member>> = BTreeMap::new();
+    let members = read_members(archive, kind)?;
+
+    for member in &members {
+        let Ok(rest) = member.path.strip_prefix(inner) else {
+            continue;
+        };
+        let mut components = rest.components();
+        let Some(name) = components.next() else {
+            continue;
+        };
+        let name = name.as_os_str().to_string_lossy().to_string();
+
+        if components.next().is_none() {
+            children.insert(name, Some(member));
+        } else {
+            children.entry(name).or_insert(None);
+        }
+    }
+
+    if children.is_empty() && !is_archive_directory(archive, kind, inner) {
+        return Err(Error::Io(io::ErrorKind::NotFound.into()));
+    }
+
+    let directory = archive.join(inner);
+    let mut entries = children
+        .into_iter()
+        .map(|(name, member)| {
+            let path = directory.join(&name);
+            let entry_type = match member {
+                Some(member) if !member.is_dir => DirectoryEntryType::File {
+                    extension: path
+                        .extension()
+                        .map(|extension| extension.to_string_lossy().to_string()),
+                    size: member.size,
+                },
+                _ => DirectoryEntryType::Directory,
+            };
+
+            DirectoryEntry::builder()
+                .name(name)
+                .path(path)
+                .modified(member.and_then(|member| member.modified))
+                .entry_type(entry_type)
+                .build()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    entries.sort();
+
+    Ok(entries)
+}
+
+fn write_member(target: &Path, is_dir: bool, reader: &mut dyn Read) -> Result<()> {
+    if is_dir {
+        fs::create_dir_all(target)?;
+    } else {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        io::copy(reader, &mut File::create(target)?)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `inner`, and everything under it when it is a directory, to `target`
+pub fn extract_member(
+    archive: &Path,
+    kind: ArchiveKind,
+    inner: &Path,
+    target: &Path,
+) -> Result<()> {
+    // joining an empty rest would add a trailing separator, which files can't be created at
+    let target_of = |path: &Path| {
+        path.strip_prefix(inner)
+            .ok()
+            .map(|rest| match rest.as_os_str().is_empty() {
+                true => target.to_owned(),
+                false => target.join(rest),
+            })
+    };
+
+    match kind {
+        ArchiveKind::Zip => {
+            let mut zip = open_zip(archive)?;
+
+            for index in 0..zip.len() {
+                let mut file = zip
+                    .by_index(index)
+                    .map_err(|e| Error::Archive(e.to_string()))?;
+
+                // links are skipped as in tars, they could point outside of `target`
+                if file.is_symlink() {
+                    continue;
+                }
+                if let Some(path) = target_of(&member_path(Path::new(file.name()))) {
+                    let is_dir = file.is_dir();
+                    write_member(&path, is_dir, &mut file)?;
+                }
+            }
+        }
+        ArchiveKind::Tar | ArchiveKind::TarGz | ArchiveKind::TarZst => {
+            for entry in open_tar(archive, kind)?.entries()? {
+                let mut entry = entry?;
+                let entry_type = entry.header().entry_type();
+
+                // links and devices are skipped, they could point outside of `target`
+                if !entry_type.is_dir() && !entry_type.is_file() {
+                    continue;
+                }
+                if let Some(path) = target_of(&member_path(&entry.path()?)) {
+                    write_member(&path, entry_type.is_dir(), &mut entry)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    use super::*;
+    use crate::config::SizeUnits;
+
+    #[test]
+    fn test_read_and_extract_zip() {
+        let root = tempfile::tempdir().unwrap();
+        let archive = root.path().join("archive.zip");
+
+        let mut zip = ZipWriter::new(File::create(&archive).unwrap());
+        zip.start_file("top.txt", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"top").unwrap();
+        zip.start_file("./inner/dir/deep.txt", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"deep").unwrap();
+        zip.finish().unwrap();
+
+        let kind = ArchiveKind::from_path(&archive).unwrap();
+        let entries = read_archive_directory(&archive, kind, Path::new("")).unwrap();
+        let names: Vec<_> = entries.iter().map(DirectoryEntry::name).collect();
+
+        assert_eq!(names, vec!["inner", "top.txt"]);
+        assert_eq!(*entries[0].entry_type(), DirectoryEntryType::Directory);
+        assert_eq!(entries[1].path(), &archive.join("top.txt"));
+        assert!(is_archive_directory(&archive, kind, Path::new("inner/dir")));
+        assert!(!is_archive_directory(&archive, kind, Path::new("top.txt")));
+
+        let target = root.path().join("inner");
+        extract_member(&archive, kind, Path::new("inner"), &target).unwrap();
+        assert_eq!(
+            fs::read_to_string(target.join("dir/deep.txt")).unwrap(),
+            "deep"
+        );
+
+        let target = root.path().join("top.txt");
+        extract_member(&archive, kind, Path::new("top.txt"), &target).unwrap();
+        assert_eq!(fs::read_to_string(target).unwrap(), "top");
+    }
+
+    #[test]
+    fn test_read_tar_gz() {
+        let root = tempfile::tempdir().unwrap();
+        let archive = root.path().join("archive.tgz");
+
+        let encoder = flate2::write::GzEncoder::new(
+            File::create(&archive).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut tar = tar::Builder::new(encoder);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(5);
+        header.set_mode(0o644);
+        tar.append_data(&mut header, "dir/file.txt", &b"hello"[..])
+            .unwrap();
+        tar.into_inner().unwrap().finish().unwrap();
+
+        let kind = ArchiveKind::from_path(&archive).unwrap();
+        let entries = read_archive_directory(&archive, kind, Path::new("dir")).unwrap();
+
+        assert_eq!(kind, ArchiveKind::TarGz);
+        assert_eq!(
+            ArchiveKind::stem(Path::new("v1.2.TAR.GZ")),
+            Some("v1.2".to_string())
+        );
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].formatted_size(SizeUnits::Binary),
+            Some("5 B".to_string())
+        );
+        assert!(read_archive_directory(&archive, kind, Path::new("missing")).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use flate2::{Compression, write::GzEncoder};
+use zip::{ZipWriter, write::SimpleFileOptions};
+
+use crate::directory_entry::{Error, Result};
+
+use super::ArchiveKind;
+
+/// Adds `source` as `name`, storing symlinks as links like tar does rather than what they point to
+fn zip_path(zip: &mut ZipWriter<File>, source: &Path, name: &Path) -> Result<()> {
+    let options = SimpleFileOptions::default();
+    let zip_error = |e: zip::result::ZipError| Error::Archive(e.to_string());
+    let file_type = fs::symlink_metadata(source)?.file_type();
+
+    if file_type.is_symlink() {
+        zip.add_symlink(
+            name.to_string_lossy(),
+            fs::read_link(source)?.to_string_lossy(),
+            options,
+        )
+        .map_err(zip_error)?;
+    } else if file_type.is_dir() {
+        zip.add_directory(name.to_string_lossy(), options)
+            .map_err(zip_error)?;
+
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            zip_path(zip, &entry.path(), &name.join(entry.file_name()))?;
+        }
+    } else {
+        zip.start_file(name.to_string_lossy(), options)
+            .map_err(zip_error)?;
+        io::copy(&mut File::open(source)?, zip)?;
+    }
+
+    Ok(())
+}
+
+fn write_tar<W: Write>(writer: W, sources: &[PathBuf]) -> Result<W> {
+    let mut tar = tar::Builder::new(writer);
+    tar.follow_symlinks(false);
+
+    for source in sources {
+        let name = source.file_name().ok_or(Error::MissingName)?;
+
+        if fs::symlink_metadata(source)?.is_dir() {
+            tar.append_dir_all(name, source)?;
+        } else {
+            tar.append_path_with_name(source, name)?;
+        }
+    }
+
+    Ok(tar.into_inner()?)
+}
+
+fn write_archive(archive: &Path, kind: ArchiveKind, sources: &[PathBuf]) -> Result<()> {
+    let file = File::create_new(archive)?;
+
+    match kind {
+        ArchiveKind::Zip => {
+            let mut zip = ZipWriter::new(file);
+
+            for source in sources {
+                let name = source.file_name().ok_or(Error::MissingName)?;
+                zip_path(&mut zip, source, Path::new(name))?;
+            }
+
+            zip.finish().map_err(|e| Error::Archive(e.to_string()))?;
+        }
+        ArchiveKind::Tar => {
+            write_tar(file, sources)?;
+        }
+        ArchiveKind::TarGz => {
+            write_tar(GzEncoder::new(file, Compression::default()), sources)?.finish()?;
+        }
+        ArchiveKind::TarZst => {
+            write_tar(zstd::Encoder::new(file, 0)?, sources)?.finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates `archive`, of the kind told by its extension, with each of `sources` at its root
+///
+/// Nothing is left behind when it fails, an existing file is never replaced
+pub fn create_archive(archive: &Path, sources: &[PathBuf]) -> Result<()> {
+    let kind =
+        ArchiveKind::from_path(archive).ok_or_else(|| Error::UnsupportedArchive(archive.into()))?;
+
+    if archive.exists() {
+        return Err(Error::AlreadyExists(archive.to_owned()));
+    }
+
+    let result = write_archive(archive, kind, sources);
+    if result.is_err() {
+        let _ = fs::remove_file(archive);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::directory_entry::{read_directory, vfs::extract_archive};
+
+    use super::*;
+
+    #[test]
+    fn test_compress_and_extract() {
+        let root = tempfile::tempdir().unwrap();
+        let sources = vec![root.path().join("dir"), root.path().join("file.txt")];
+        fs::create_dir_all(sources[0].join("nested")).unwrap();
+        fs::write(sources[0].join("nested/deep.txt"), "deep").unwrap();
+        fs::write(&sources[1], "file").unwrap();
+        fs::write(root.path().join("secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(root.path().join("secret.txt"), sources[0].join("link"))
+            .unwrap();
+
+        for name in ["both.zip", "both.tar.zst", "both.tar.gz"] {
+            let archive = root.path().join(name);
+            create_archive(&archive, &sources).unwrap();
+
+            let names: Vec<_> = read_directory(&archive)
+                .unwrap()
+                .iter()
+                .map(|entry| entry.name().to_string())
+                .collect();
+            assert_eq!(names, vec!["dir", "file.txt"]);
+
+            // more than one entry at the root goes in a directory named after the archive
+            let destination = root.path().join(format!("out-{name}"));
+            let target = extract_archive(&archive, &destination).unwrap();
+            assert_eq!(target, destination.join("both"));
+            assert_eq!(
+                fs::read_to_string(target.join("dir/nested/deep.txt")).unwrap(),
+                "deep"
+            );
+            // stored as a link, which extracting skips, instead of a copy of what it points to
+            assert!(fs::symlink_metadata(target.join("dir/link")).is_err());
+        }
+
+        let archive = root.path().join("single.tar");
+        create_archive(&archive, &sources[..1]).unwrap();
+        let destination = root.path().join("out-single");
+        assert_eq!(
+            extract_archive(&archive, &destination).unwrap(),
+            destination.join("dir")
+        );
+        assert!(matches!(
+            create_archive(&archive, &sources),
+            Err(Error::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            create_archive(&root.path().join("plain.txt"), &sources),
+            Err(Error::UnsupportedArchive(_))
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+mod archive;
+mod compress;
+
+use std::{
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use tempfile::TempDir;
+
+pub use archive::ArchiveKind;
+use archive::{extract_member, is_archive_directory, read_archive_directory, top_level_names};
+pub use compress::create_archive;
+
+use super::{DirectoryEntry, Error, Result};
+
+/// Where the entries of a directory path are read from, archives are browsed as directories
+/// with the path of the archive file as their root
+#[derive(Debug, PartialEq)]
+pub enum Location {
+    Local(PathBuf),
+    Archive {
+        archive: PathBuf,
+        kind: ArchiveKind,
+        /// Relative to the root of the archive, empty for the root itself
+        inner: PathBuf,
+    },
+}
+
+impl Location {
+    /// Finds the archive `path` points into, if any, from its closest existing ancestor
+    pub fn resolve(path: &Path) -> Self {
+        if path.is_dir() {
+            return Self::Local(path.to_owned());
+        }
+
+        let Some(existing) = path.ancestors().find(|ancestor| ancestor.exists()) else {
+            return Self::Local(path.to_owned());
+        };
+
+        match ArchiveKind::from_path(existing) {
+            Some(kind) if existing.is_file() => Self::Archive {
+                archive: existing.to_owned(),
+                kind,
+                inner: path
+                    .strip_prefix(existing)
+                    .map(Path::to_owned)
+                    .unwrap_or_default(),
+            },
+            _ => Self::Local(path.to_owned()),
+        }
+    }
+
+    pub fn is_directory(&self) -> bool {
+        match self {
+            Self::Local(path) => path.is_dir(),
+            Self::Archive {
+                archive,
+                kind,
+                inner,
+            } => is_archive_directory(archive, *kind, inner),
+        }
+    }
+
+    pub fn read_directory(&self) -> Result<Vec<DirectoryEntry>> {
+        match self {
+            Self::Local(path) => super::read_directory::read_local_directory(path),
+            Self::Archive {
+                archive,
+                kind,
+                inner,
+            } => read_archive_directory(archive, *kind, inner),
+        }
+    }
+}
+
+/// Whether `path` is a directory on disk or inside an archive
+pub fn is_directory(path: &Path) -> bool {
+    Location::resolve(path).is_directory()
+}
+
+/// Whether `path` is an entry inside an archive, the archive file itself is not
+pub fn is_virtual(path: &Path) -> bool {
+    matches!(
+        Location::resolve(path),
+        Location::Archive { inner, .. } if inner.components().next().is_some()
+    )
+}
+
+/// Whether the directory `path` is browsed inside an archive, or is its root, and so can't be
+/// written to
+pub fn is_virtual_directory(path: &Path) -> bool {
+    matches!(Location::resolve(path), Location::Archive { .. })
+}
+
+/// Whether `path` is an archive file that can be browsed
+pub fn is_archive(path: &Path) -> bool {
+    path.is_file() && ArchiveKind::from_path(path).is_some()
+}
+
+/// Extracts the entry at `path` inside an archive into `destination`, returning where it went
+pub fn extract(path: &Path, destination: &Path) -> Result<PathBuf> {
+    let Location::Archive {
+        archive,
+        kind,
+        inner,
+    } = Location::resolve(path)
+    else {
+        return Err(Error::NotInArchive(path.to_owned()));
+    };
+
+    let name = inner
+        .file_name()
+        .or_else(|| archive.file_name())
+        .ok_or(Error::MissingName)?;
+    let target = destination.join(name);
+
+    if target.exists() {
+        return Err(Error::AlreadyExists(target));
+    }
+
+    extract_member(&archive, kind, &inner, &target)?;
+
+    Ok(target)
+}
+
+/// Extracts the whole `archive` into `destination`, inside a directory named after the archive
+/// when it has more than one entry at its root so they don't spill over `destination`
+pub fn extract_archive(archive: &Path, destination: &Path) -> Result<PathBuf> {
+    let kind =
+        ArchiveKind::from_path(archive).ok_or_else(|| Error::UnsupportedArchive(archive.into()))?;
+    let names = top_level_names(archive, kind)?;
+
+    let (target, inner) = match names.first() {
+        Some(name) if names.len() == 1 => (destination.join(name), PathBuf::from(name)),
+        _ => {
+            let stem = ArchiveKind::stem(archive).ok_or(Error::MissingName)?;
+            (destination.join(stem), PathBuf::new())
+        }
+    };
+
+    if target.exists() {
+        return Err(Error::AlreadyExists(target));
+    }
+    if inner.as_os_str().is_empty() {
+        std::fs::create_dir_all(&target)?;
+    }
+
+    extract_member(archive, kind, &inner, &target)?;
+
+    Ok(target)
+}
+
+/// The private directory entries are extracted to for opening, created on first use
+static TEMP_DIRECTORY: Mutex<Option<TempDir>> = Mutex::new(None);
+
+/// Extracts the entry at `path` inside an archive to a temporary directory so it can be opened,
+/// replacing what an earlier call extracted there
+pub fn extract_to_temp(path: &Path) -> Result<PathBuf> {
+    let directory = {
+        let mut temp = TEMP_DIRECTORY.lock().unwrap_or_else(|e| e.into_inner());
+        match &*temp {
+            Some(temp) => temp.path().to_owned(),
+            None => temp
+                .insert(
+                    tempfile::Builder::new()
+                        .prefix("arfima-")
+                        .permissions(fs::Permissions::from_mode(0o700))
+                        .tempdir()?,
+                )
+                .path()
+                .to_owned(),
+        }
+    };
+    let target = directory.join(path.file_name().ok_or(Error::MissingName)?);
+
+    if target.is_dir() {
+        fs::remove_dir_all(&target)?;
+    } else if target.exists() {
+        fs::remove_file(&target)?;
+    }
+
+    extract(path, &directory)
+}
+
+/// Deletes the directory of `extract_to_temp` with everything extracted to it
+pub fn remove_temp_directory() {
+    let temp = TEMP_DIRECTORY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+
+    drop(temp);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_to_temp_is_private_and_removed() {
+        let root = tempfile::tempdir().unwrap();
+        let file = root.path().join("file.txt");
+        let archive = root.path().join("archive.tar");
+        fs::write(&file, "content").unwrap();
+        create_archive(&archive, &[file]).unwrap();
+
+        let extracted = extract_to_temp(&archive.join("file.txt")).unwrap();
+        let directory = extracted.parent().unwrap().to_owned();
+
+        assert_eq!(fs::read_to_string(&extracted).unwrap(), "content");
+        assert_eq!(
+            fs::metadata(&directory).unwrap().permissions().mode() & 0o777,
+            0o700
+        );
+        assert_eq!(
+            extract_to_temp(&archive.join("file.txt")).unwrap(),
+            extracted
+        );
+
+        remove_temp_directory();
+        assert!(!directory.exists());
+    }
+}
@@ -0,0 +1,240 @@
+use serde::{Deserialize, Serialize};
+
+/// Everything a key sequence can be bound to in normal mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    QuitAll,
+    QuitChoosingDirectory,
+    Reset,
+    Help,
+    CommandLine,
+    MoveDown,
+    MoveUp,
+    GoParent,
+    Open,
+    OpenWith,
+    Edit,
+    Add,
+    Rename,
+    Remove,
+    Mark,
+    JumpToTop,
+    JumpToBottom,
+    NextWindow,
+    PrevWindow,
+    FocusLeft,
+    FocusDown,
+    FocusUp,
+    FocusRight,
+    SplitHorizontal,
+    SplitVertical,
+    IncreaseWidth,
+    DecreaseWidth,
+    IncreaseHeight,
+    DecreaseHeight,
+    OnlyWindow,
+    EqualizeWindows,
+    MaximizeWidth,
+    MaximizeHeight,
+    SwapWindow,
+    RotateWindows,
+    MoveWindowFarLeft,
+    MoveWindowFarBottom,
+    MoveWindowFarTop,
+    MoveWindowFarRight,
+    ToggleCommonEntries,
+    SwitchPane,
+    CopyToOtherPane,
+    MoveToOtherPane,
+    Extract,
+    /// Unbinds a default sequence without binding anything in its place
+    Nop,
+}
+
+impl Action {
+    /// All bindable actions in the order they are listed in help
+    pub const ALL: [Action; 45] = [
+        Self::Quit,
+        Self::QuitAll,
+        Self::QuitChoosingDirectory,
+        Self::Reset,
+        Self::Help,
+        Self::CommandLine,
+        Self::MoveDown,
+        Self::MoveUp,
+        Self::GoParent,
+        Self::Open,
+        Self::OpenWith,
+        Self::Edit,
+        Self::Add,
+        Self::Rename,
+        Self::Remove,
+        Self::Mark,
+        Self::JumpToTop,
+        Self::JumpToBottom,
+        Self::NextWindow,
+        Self::PrevWindow,
+        Self::FocusLeft,
+        Self::FocusDown,
+        Self::FocusUp,
+        Self::FocusRight,
+        Self::SplitHorizontal,
+        Self::SplitVertical,
+        Self::IncreaseWidth,
+        Self::DecreaseWidth,
+        Self::IncreaseHeight,
+        Self::DecreaseHeight,
+        Self::OnlyWindow,
+        Self::EqualizeWindows,
+        Self::MaximizeWidth,
+        Self::MaximizeHeight,
+        Self::SwapWindow,
+        Self::RotateWindows,
+        Self::MoveWindowFarLeft,
+        Self::MoveWindowFarBottom,
+        Self::MoveWindowFarTop,
+        Self::MoveWindowFarRight,
+        Self::ToggleCommonEntries,
+        Self::SwitchPane,
+        Self::CopyToOtherPane,
+        Self::MoveToOtherPane,
+        Self::Extract,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::QuitAll => "quit all",
+            Self::QuitChoosingDirectory => "quit and cd",
+            Self::Reset => "reset",
+            Self::Help => "enter help mode",
+            Self::CommandLine => "command line",
+            Self::MoveDown => "navigate down",
+            Self::MoveUp => "navigate up",
+            Self::GoParent => "go up",
+            Self::Open => "open",
+            Self::OpenWith => "open with",
+            Self::Edit => "edit",
+            Self::Add => "add entry",
+            Self::Rename => "rename entry",
+            Self::Remove => "remove entry",
+            Self::Mark => "mark entry",
+            Self::JumpToTop => "jump to top",
+            Self::JumpToBottom => "jump to bottom",
+            Self::NextWindow => "next window",
+            Self::PrevWindow => "prev window",
+            Self::FocusLeft => "window left",
+            Self::FocusDown => "window below",
+            Self::FocusUp => "window above",
+            Self::FocusRight => "window right",
+            Self::SplitHorizontal => "split h",
+            Self::SplitVertical => "split v",
+            Self::IncreaseWidth => "inc win width",
+            Self::DecreaseWidth => "dec win width",
+            Self::IncreaseHeight => "inc win height",
+            Self::DecreaseHeight => "dec win height",
+            Self::OnlyWindow => "only window",
+            Self::EqualizeWindows => "equalize windows",
+            Self::MaximizeWidth => "max win width",
+            Self::MaximizeHeight => "max win height",
+            Self::SwapWindow => "swap window",
+            Self::RotateWindows => "rotate windows",
+            Self::MoveWindowFarLeft => "move win left",
+            Self::MoveWindowFarBottom => "move win bottom",
+            Self::MoveWindowFarTop => "move win top",
+            Self::MoveWindowFarRight => "move win right",
+            Self::ToggleCommonEntries => "common entries",
+            Self::SwitchPane => "switch pane",
+            Self::CopyToOtherPane => "copy to pane",
+            Self::MoveToOtherPane => "move to pane",
+            Self::Extract => "extract",
+            Self::Nop => "nop",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Quit => "Close the focused window, quitting with the last one",
+            Self::QuitAll => "Quit the application",
+            Self::QuitChoosingDirectory => {
+                "Quit every window, leaving the shell in the focused directory with `--init`"
+            }
+            Self::Reset => "Reset the normal mode",
+            Self::Help => "Show help modal",
+            Self::CommandLine => "Start typing a command",
+            Self::MoveDown => "Move selection down",
+            Self::MoveUp => "Move selection up",
+            Self::GoParent => "Navigate to parent directory",
+            Self::Open => "Open selected file or directory",
+            Self::OpenWith => "Choose the application to open selected file with",
+            Self::Edit => "Open selected file in $VISUAL, $EDITOR or $PAGER inside the terminal",
+            Self::Add => "Create a new file or directory changing the mode to ADDING",
+            Self::Rename => "Rename selected entry by change mode to RENAMING",
+            Self::Remove => "Delete selected entry by change mode to REMOVING",
+            Self::Mark => "Toggle the mark on selected entry and move down",
+            Self::JumpToTop => "Select first entry, or the nth entry with a count",
+            Self::JumpToBottom => "Select last entry",
+            Self::NextWindow => "Iterate through windows to the next window",
+            Self::PrevWindow => "Iterate through windows to the previous window",
+            Self::FocusLeft => "Focus the window left of the focused one on the screen",
+            Self::FocusDown => "Focus the window below the focused one on the screen",
+            Self::FocusUp => "Focus the window above the focused one on the screen",
+            Self::FocusRight => "Focus the window right of the focused one on the screen",
+            Self::SplitHorizontal => "Open a new window next to the focused one",
+            Self::SplitVertical => "Open a new window below the focused one",
+            Self::IncreaseWidth => "Increase focused window size horizontally",
+            Self::DecreaseWidth => "Decrease focused window size horizontally",
+            Self::IncreaseHeight => "Increase focused window size vertically",
+            Self::DecreaseHeight => "Decrease focused window size vertically",
+            Self::OnlyWindow => "Close every window except the focused one",
+            Self::EqualizeWindows => "Bring every window back to its default size",
+            Self::MaximizeWidth => "Make the focused window as wide as possible",
+            Self::MaximizeHeight => "Make the focused window as tall as possible",
+            Self::SwapWindow => "Exchange the focused window with the next one",
+            Self::RotateWindows => "Rotate the windows of the focused split",
+            Self::MoveWindowFarLeft => "Move the focused window to the far left at full height",
+            Self::MoveWindowFarBottom => "Move the focused window to the bottom at full width",
+            Self::MoveWindowFarTop => "Move the focused window to the top at full width",
+            Self::MoveWindowFarRight => "Move the focused window to the far right at full height",
+            Self::ToggleCommonEntries => "Toggle the common entries window",
+            Self::SwitchPane => "Focus the other pane in commander mode, the next window otherwise",
+            Self::CopyToOtherPane => {
+                "Copy the marked entries, or the selected one, to the other pane in commander mode"
+            }
+            Self::MoveToOtherPane => {
+                "Move the marked entries, or the selected one, to the other pane in commander mode"
+            }
+            Self::Extract => {
+                "Extract the selected archive, or entry of a browsed archive, next to the archive"
+            }
+            Self::Nop => "Do nothing",
+        }
+    }
+
+    /// Whether a `{n}` count typed before the sequence changes what the action does
+    pub fn takes_count(&self) -> bool {
+        matches!(
+            self,
+            Self::MoveDown
+                | Self::MoveUp
+                | Self::GoParent
+                | Self::JumpToTop
+                | Self::NextWindow
+                | Self::PrevWindow
+                | Self::FocusLeft
+                | Self::FocusDown
+                | Self::FocusUp
+                | Self::FocusRight
+                | Self::SplitHorizontal
+                | Self::SplitVertical
+                | Self::IncreaseWidth
+                | Self::DecreaseWidth
+                | Self::IncreaseHeight
+                | Self::DecreaseHeight
+                | Self::SwapWindow
+                | Self::RotateWindows
+        )
+    }
+}
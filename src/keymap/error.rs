@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    EmptySequence,
+    UnclosedKey(String),
+    UnknownKey(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySequence => write!(f, "Key sequence is empty"),
+            Self::UnclosedKey(key) => write!(f, "Missing '>' after '{key}'"),
+            Self::UnknownKey(key) => write!(f, "Unknown key '<{key}>'"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
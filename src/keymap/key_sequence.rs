@@ -0,0 +1,135 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Error, Key};
+
+/// An ordered list of keys written in vim notation, e.g. `gg`, `<C-w>v` or `<Space>n`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeySequence(Vec<Key>);
+
+impl KeySequence {
+    pub fn keys(&self) -> &[Key] {
+        &self.0
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut keys = Vec::new();
+        let mut rest = s;
+
+        while let Some(c) = rest.chars().next() {
+            if c == '<' && rest.len() > 1 {
+                let end = rest.find('>').ok_or(Error::UnclosedKey(rest.to_string()))?;
+                keys.push(Key::parse_notation(&rest[1..end])?);
+                rest = &rest[end + 1..];
+            } else {
+                keys.push(Key::from(c));
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+
+        if keys.is_empty() {
+            return Err(Error::EmptySequence);
+        }
+
+        Ok(Self(keys))
+    }
+}
+
+impl TryFrom<String> for KeySequence {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<KeySequence> for String {
+    fn from(value: KeySequence) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<Vec<Key>> for KeySequence {
+    fn from(value: Vec<Key>) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for KeySequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for key in &self.0 {
+            write!(f, "{key}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_and_notation() {
+        let sequence: KeySequence = "g<C-w>v<Space><CR>".parse().unwrap();
+
+        assert_eq!(
+            sequence.keys(),
+            &[
+                Key::from('g'),
+                Key::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
+                Key::from('v'),
+                Key::from(' '),
+                Key::new(KeyCode::Enter, KeyModifiers::NONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for notation in [
+            "gg", "<C-w>+", "<Space>n", "<lt>", "G", "<F5>", "<S-Tab>", "<A-x>",
+        ] {
+            let sequence: KeySequence = notation.parse().unwrap();
+            assert_eq!(sequence.to_string(), notation);
+        }
+    }
+
+    #[test]
+    fn test_key_events_are_normalized() {
+        let shifted = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        let control = KeyEvent::new(KeyCode::Char('C'), KeyModifiers::CONTROL);
+        let back_tab = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+
+        assert_eq!(Key::from(&shifted), Key::from('G'));
+        assert_eq!(
+            Key::from(&control),
+            "<C-c>".parse::<KeySequence>().unwrap().keys()[0]
+        );
+        assert_eq!(
+            Key::from(&back_tab),
+            "<S-Tab>".parse::<KeySequence>().unwrap().keys()[0]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<KeySequence>(), Err(Error::EmptySequence));
+        assert_eq!(
+            "<C-w".parse::<KeySequence>(),
+            Err(Error::UnclosedKey("<C-w".into()))
+        );
+        assert_eq!(
+            "<Nope>".parse::<KeySequence>(),
+            Err(Error::UnknownKey("Nope".into()))
+        );
+    }
+}
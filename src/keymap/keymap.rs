@@ -0,0 +1,219 @@
+use std::sync::LazyLock;
+
+use crate::data::keys::NORMAL_KEYS;
+
+use super::{Action, Error, Key, KeySequence, Mapping};
+
+static DEFAULT_KEYMAP: LazyLock<Keymap> = LazyLock::new(Keymap::new);
+
+/// The normal mode key sequence table, defaults first and user bindings applied over them
+#[derive(Clone, Debug, Default)]
+pub struct Keymap {
+    normal: Vec<(KeySequence, Mapping)>,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        let mut keymap = Self::default();
+
+        for (sequence, action) in NORMAL_KEYS {
+            // the defaults are covered by a test, they always parse
+            let _ = keymap.bind(sequence, action);
+        }
+
+        keymap
+    }
+
+    /// The built-in bindings, used to resolve the keys of `noremap` mappings
+    pub fn defaults() -> &'static Keymap {
+        &DEFAULT_KEYMAP
+    }
+
+    /// Binds `sequence` to `mapping`, replacing any previous binding of the same sequence
+    /// `Action::Nop` only removes the previous binding
+    pub fn bind(&mut self, sequence: &str, mapping: impl Into<Mapping>) -> Result<(), Error> {
+        let sequence: KeySequence = sequence.parse()?;
+        let mapping = mapping.into();
+
+        self.normal.retain(|(existing, _)| *existing != sequence);
+        if mapping != Mapping::Action(Action::Nop) {
+            self.normal.push((sequence, mapping));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the binding of `sequence`, bringing back its default binding if it has one
+    pub fn unbind(&mut self, sequence: &KeySequence) {
+        self.normal.retain(|(existing, _)| existing != sequence);
+
+        if let Some(mapping) = Self::defaults().get(sequence.keys()) {
+            self.normal.push((sequence.clone(), mapping.clone()));
+        }
+    }
+
+    pub fn get(&self, keys: &[Key]) -> Option<&Mapping> {
+        self.normal
+            .iter()
+            .find(|(sequence, _)| sequence.keys() == keys)
+            .map(|(_, mapping)| mapping)
+    }
+
+    /// Whether some binding is longer than `keys` and starts with them
+    pub fn is_prefix(&self, keys: &[Key]) -> bool {
+        self.normal.iter().any(|(sequence, _)| {
+            sequence.keys().len() > keys.len() && sequence.keys().starts_with(keys)
+        })
+    }
+
+    /// Feeds `key` into the `pending` sequence
+    ///
+    /// Returns `None` when the key does not continue any binding, otherwise the mappings completed by
+    /// it, which is empty while a longer binding is still possible. When a sequence is both bound and
+    /// a prefix of a longer one it waits, then runs the shorter one once the next key diverges
+    pub fn feed(&self, pending: &mut Vec<Key>, key: Key) -> Option<Vec<Mapping>> {
+        pending.push(key);
+
+        if self.is_prefix(pending) {
+            return Some(Vec::new());
+        }
+
+        if let Some(mapping) = self.get(pending).cloned() {
+            pending.clear();
+            return Some(vec![mapping]);
+        }
+
+        pending.pop();
+
+        if !pending.is_empty()
+            && let Some(mapping) = self.get(pending).cloned()
+        {
+            pending.clear();
+
+            let mut mappings = vec![mapping];
+            mappings.extend(self.feed(pending, key).unwrap_or_default());
+
+            return Some(mappings);
+        }
+
+        pending.clear();
+
+        None
+    }
+
+    /// Returns the sequences bound to `action` in the order they were bound
+    pub fn sequences_for(&self, action: Action) -> impl Iterator<Item = &KeySequence> {
+        self.normal
+            .iter()
+            .filter(move |(_, bound)| *bound == Mapping::Action(action))
+            .map(|(sequence, _)| sequence)
+    }
+
+    /// Returns the sequences bound to keys instead of an action with the keys they type
+    pub fn key_mappings(&self) -> impl Iterator<Item = (&KeySequence, &KeySequence)> {
+        self.normal
+            .iter()
+            .filter_map(|(sequence, mapping)| match mapping {
+                Mapping::Keys { keys, .. } => Some((sequence, keys)),
+                Mapping::Action(_) => None,
+            })
+    }
+
+    /// Returns the actions that have at least one binding in help order
+    pub fn bound_actions(&self) -> impl Iterator<Item = Action> + '_ {
+        Action::ALL
+            .into_iter()
+            .filter(|action| self.sequences_for(*action).next().is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(sequence: &str) -> Vec<Key> {
+        sequence.parse::<KeySequence>().unwrap().keys().to_vec()
+    }
+
+    #[test]
+    fn test_defaults_parse() {
+        let keymap = Keymap::new();
+
+        assert_eq!(keymap.normal.len(), NORMAL_KEYS.len());
+    }
+
+    #[test]
+    fn test_feed_multi_key_sequence() {
+        let keymap = Keymap::new();
+        let mut pending = Vec::new();
+
+        assert_eq!(keymap.feed(&mut pending, Key::from('g')), Some(vec![]));
+        assert_eq!(
+            keymap.feed(&mut pending, Key::from('g')),
+            Some(vec![Action::JumpToTop.into()])
+        );
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_feed_unknown_key_clears_pending() {
+        let keymap = Keymap::new();
+        let mut pending = keys("<C-w>");
+
+        assert_eq!(keymap.feed(&mut pending, Key::from('z')), None);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_feed_ambiguous_runs_shorter_binding_then_next_key() {
+        let mut keymap = Keymap::new();
+        keymap.bind("x", Action::Remove).unwrap();
+        keymap.bind("xx", Action::Mark).unwrap();
+        let mut pending = Vec::new();
+
+        assert_eq!(keymap.feed(&mut pending, Key::from('x')), Some(vec![]));
+        assert_eq!(
+            keymap.feed(&mut pending, Key::from('j')),
+            Some(vec![Action::Remove.into(), Action::MoveDown.into()])
+        );
+    }
+
+    #[test]
+    fn test_bind_overrides_and_unbinds() {
+        let mut keymap = Keymap::new();
+        keymap.bind("j", Action::MoveUp).unwrap();
+        keymap.bind("q", Action::Nop).unwrap();
+
+        assert_eq!(keymap.get(&keys("j")), Some(&Action::MoveUp.into()));
+        assert_eq!(keymap.get(&keys("q")), None);
+        assert!(!keymap.bound_actions().any(|action| action == Action::Quit));
+        assert!(keymap.bind("<Bogus>", Action::Quit).is_err());
+    }
+
+    #[test]
+    fn test_unbind_restores_default() {
+        let mut keymap = Keymap::new();
+        keymap.bind("j", Action::MoveUp).unwrap();
+        keymap.bind("gh", Action::Quit).unwrap();
+
+        keymap.unbind(&"j".parse().unwrap());
+        keymap.unbind(&"gh".parse().unwrap());
+
+        assert_eq!(keymap.get(&keys("j")), Some(&Action::MoveDown.into()));
+        assert_eq!(keymap.get(&keys("gh")), None);
+    }
+
+    #[test]
+    fn test_key_mappings() {
+        let mut keymap = Keymap::new();
+        let mapping = Mapping::Keys {
+            keys: ":cd ~<CR>".parse().unwrap(),
+            noremap: false,
+        };
+        keymap.bind("gh", mapping.clone()).unwrap();
+
+        assert_eq!(keymap.get(&keys("gh")), Some(&mapping));
+        assert_eq!(keymap.key_mappings().count(), 1);
+        assert!(Keymap::defaults().get(&keys("gh")).is_none());
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value},
+};
+
+use super::{Action, KeySequence};
+
+/// What a key sequence is bound to, either a built-in action or keys typed in its place
+///
+/// In `arfima.toml` an action is written by name and keys as a table:
+///
+/// ```toml
+/// [keys.normal]
+/// "x" = "remove"
+/// "gh" = { keys = ":cd ~<CR>" }
+/// "gw" = { keys = ":cd ~/work<CR>", noremap = true }
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged, try_from = "RawMapping")]
+pub enum Mapping {
+    Action(Action),
+    Keys {
+        keys: KeySequence,
+        /// When set the keys are not looked up in user mappings, only in the defaults
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        noremap: bool,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawKeys {
+    keys: KeySequence,
+    #[serde(default)]
+    noremap: bool,
+}
+
+/// Action names are resolved after picking the variant so a misspelled one reports the unknown
+/// variant instead of not matching any form
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawMapping {
+    Action(String),
+    Keys(RawKeys),
+}
+
+impl TryFrom<RawMapping> for Mapping {
+    type Error = value::Error;
+
+    fn try_from(value: RawMapping) -> Result<Self, Self::Error> {
+        match value {
+            RawMapping::Action(name) => {
+                Action::deserialize(name.into_deserializer()).map(Self::Action)
+            }
+            RawMapping::Keys(RawKeys { keys, noremap }) => Ok(Self::Keys { keys, noremap }),
+        }
+    }
+}
+
+impl From<Action> for Mapping {
+    fn from(value: Action) -> Self {
+        Self::Action(value)
+    }
+}
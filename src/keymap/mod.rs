@@ -0,0 +1,14 @@
+mod action;
+mod error;
+mod key;
+mod key_sequence;
+#[allow(clippy::module_inception)]
+mod keymap;
+mod mapping;
+
+pub use action::Action;
+pub use error::Error;
+pub use key::Key;
+pub use key_sequence::KeySequence;
+pub use keymap::Keymap;
+pub use mapping::Mapping;
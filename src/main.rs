@@ -1,58 +1,84 @@
 #![allow(dead_code)]
 
 mod app;
+mod cli;
 mod command;
 mod config;
 mod data;
 mod directory_entry;
+mod keymap;
+mod plugin;
+mod remote;
+mod state;
 mod types;
 mod utils;
 
-use std::{env, path::PathBuf};
+use std::{
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
 
-use directories::ProjectDirs;
-use log::{error, info};
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
+use log::{LevelFilter, error};
 use stderrlog::LogLevelNum;
 
 use crate::{
-    app::App,
+    app::{App, AppEvent, Picker},
+    cli::Cli,
     config::Config,
     data::{CONFIG_FILE_NAME, package},
+    state::LAST_SESSION,
+    utils::FileLogger,
 };
 
-fn main() -> color_eyre::Result<()> {
-    stderrlog::new()
-        .module(module_path!())
-        .quiet(false)
-        .verbosity(LogLevelNum::Info)
-        .init()
-        .unwrap();
-
-    let directory = match env::args().nth(1) {
-        Some(p) => {
-            let path = PathBuf::from(p).canonicalize().unwrap_or_else(|_| {
-                error!("Invalid path provided.");
-                std::process::exit(1);
-            });
-
-            if path.is_dir() {
-                path
-            } else {
-                error!("The path provided needs to be a directory");
-                std::process::exit(1);
-            }
-        }
-        None => env::current_dir().unwrap_or_else(|_| {
-            error!("Failed to get current directory.");
-            std::process::exit(1);
-        }),
+/// Where picked paths go, stdout is handed to the terminal so the UI stays out of the output
+fn open_picker_output(path: &Path) -> io::Result<File> {
+    if path != Path::new("-") {
+        return File::create(path);
+    }
+
+    let stdout = rustix::io::dup(io::stdout())?;
+    let tty = File::options().read(true).write(true).open("/dev/tty")?;
+    rustix::stdio::dup2_stdout(&tty)?;
+
+    Ok(File::from(stdout))
+}
+
+/// `--remote`, prints the answers of the running instance and fails when any request failed
+fn run_remote(cli: &Cli) -> remote::Result<bool> {
+    let socket = match cli.socket() {
+        Some(socket) => socket.to_owned(),
+        None => remote::find_socket()?,
     };
 
-    let config_file = match ProjectDirs::from(
-        package::get().tld(),
-        package::get().domain(),
-        package::get().application(),
-    ) {
+    let answers = remote::send(&socket, cli.remote())?;
+
+    for answer in &answers {
+        println!("{answer}");
+    }
+
+    Ok(answers.iter().all(|answer| answer["ok"] == true))
+}
+
+/// The config to start with, the file it belongs to and why it could not be loaded
+fn load_config(cli: &Cli) -> (Config, PathBuf, Option<config::Error>) {
+    if cli.no_config() {
+        return (Config::default(), PathBuf::new(), None);
+    }
+
+    if let Some(path) = cli.config() {
+        return match Config::try_from(path.to_owned()) {
+            Ok(config) => (config, path.to_owned(), None),
+            Err(e) => (Config::default(), path.to_owned(), Some(e)),
+        };
+    }
+
+    let config_file = match package::project_dirs() {
         Some(proj_dirs) => proj_dirs.config_dir().to_owned().join(CONFIG_FILE_NAME),
         None => {
             error!(
@@ -62,21 +88,126 @@ fn main() -> color_eyre::Result<()> {
         }
     };
 
-    color_eyre::install()?;
+    let (config, error) = match Config::try_from(config_file.clone()) {
+        Ok(config) => (config, None),
+        Err(config::Error::Io(e)) if e.kind() == io::ErrorKind::NotFound => {
+            match Config::write_default(config_file.clone()) {
+                Ok(config) => (config, None),
+                Err(e) => (Config::default(), Some(e)),
+            }
+        }
+        Err(e) => (Config::default(), Some(e)),
+    };
+
+    (config, config_file, error)
+}
+
+fn main() -> color_eyre::Result<()> {
+    let cli = Cli::parse();
 
-    let config = Config::try_from(config_file.clone()).unwrap_or_else(|e| {
-        error!(
-            "Failed to get config from file({}): {e}",
-            config_file.to_string_lossy()
-        );
-        info!("Using the default config.");
-        Config::default()
+    if let Some(shell) = cli.init() {
+        print!("{}", shell.init_script());
+        return Ok(());
+    }
+
+    if !cli.remote().is_empty() {
+        match run_remote(&cli) {
+            Ok(true) => return Ok(()),
+            Ok(false) => std::process::exit(1),
+            Err(e) => Cli::command().error(ErrorKind::Io, e).exit(),
+        }
+    }
+
+    match cli.log_file() {
+        Some(path) => {
+            if let Err(e) = FileLogger::init(path, module_path!(), LevelFilter::Info) {
+                Cli::command()
+                    .error(ErrorKind::Io, format!("{}: {e}", path.display()))
+                    .exit();
+            }
+        }
+        None => stderrlog::new()
+            .module(module_path!())
+            .quiet(false)
+            .verbosity(LogLevelNum::Info)
+            .init()
+            .unwrap(),
+    }
+
+    let layout = cli
+        .layout()
+        .unwrap_or_else(|e| Cli::command().error(ErrorKind::ValueValidation, e).exit());
+
+    let picker = cli
+        .choose()
+        .map(|(path, multiple)| match open_picker_output(path) {
+            Ok(output) => Picker::new(output, multiple, cli.print0()),
+            Err(e) => Cli::command()
+                .error(ErrorKind::Io, format!("{}: {e}", path.display()))
+                .exit(),
+        });
+
+    let directory = std::env::current_dir().unwrap_or_else(|_| {
+        error!("Failed to get current directory.");
+        std::process::exit(1);
     });
 
+    color_eyre::install()?;
+
+    // an invalid config is reported inside the UI, which clears anything logged here
+    let (config, config_file, config_error) = load_config(&cli);
+
     let terminal = ratatui::init();
-    let (app, tx) = App::new(directory.to_string_lossy().as_ref(), config)?;
+    execute!(io::stdout(), EnableMouseCapture)?;
+    let (mut app, tx) = App::new(directory.to_string_lossy().as_ref(), config, config_file)?;
+
+    let remote_tx = tx.clone();
+    let server = remote::Server::start(move |request, reply| {
+        remote_tx.send(AppEvent::Remote { request, reply }).is_ok()
+    });
+    let _server = match server {
+        Ok(server) => Some(server),
+        Err(e) => {
+            app.error = Some(e.into());
+            None
+        }
+    };
+
+    if let Some(server) = &_server {
+        app.set_socket_path(server.path().to_owned());
+    }
+
+    if let Some(picker) = picker {
+        app.set_picker(picker);
+    }
+
+    if let Some(file) = cli.choosedir() {
+        app.set_choosedir(file.to_owned());
+    }
+
+    let restored = match (cli.session(), layout) {
+        (Some(name), _) => app.restore_session(name),
+        (None, Some(layout)) => layout.build(&app.config).map(|window| app.window = window),
+        (None, None) if app.config.session().restore() => {
+            app.restore_session_if_saved(LAST_SESSION)
+        }
+        (None, None) => Ok(()),
+    };
+    if let Err(e) = restored {
+        app.error = Some(e);
+    }
+
+    for command in cli.commands() {
+        app.execute_command_line(command);
+    }
+
+    if let Some(e) = config_error {
+        app.error = Some(e.into());
+    }
+
     let result = app.run(terminal, &tx);
 
+    let _ = execute!(io::stdout(), DisableMouseCapture);
     ratatui::restore();
 
     Ok(result?)
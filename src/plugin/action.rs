@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// What a plugin asks the app to do, one JSON object per line of its output
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum PluginAction {
+    /// Changes the directory of the focused window
+    Cd { path: PathBuf },
+    /// Selects the entry at the path in the focused window
+    Select { path: PathBuf },
+    /// Reads the directories of the windows again
+    Refresh,
+    /// Shows the text in the status line
+    Message { text: String },
+    /// Opens the path the way opening an entry does
+    Open { path: PathBuf },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_actions() {
+        assert_eq!(
+            serde_json::from_str::<PluginAction>(r#"{"action": "cd", "path": "/tmp"}"#).unwrap(),
+            PluginAction::Cd {
+                path: PathBuf::from("/tmp")
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<PluginAction>(r#"{"action": "refresh"}"#).unwrap(),
+            PluginAction::Refresh
+        );
+        assert!(serde_json::from_str::<PluginAction>(r#"{"action": "delete"}"#).is_err());
+        assert!(serde_json::from_str::<PluginAction>(r#"{"action": "message"}"#).is_err());
+    }
+}
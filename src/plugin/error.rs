@@ -0,0 +1,54 @@
+use std::{fmt::Display, io, process::ExitStatus};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The plugin exited unsuccessfully, with the last line it wrote to stderr
+    Failed {
+        name: String,
+        status: ExitStatus,
+        stderr: String,
+    },
+    InvalidAction {
+        name: String,
+        line: String,
+        message: String,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO error: {e}"),
+            Self::Failed {
+                name,
+                status,
+                stderr,
+            } => {
+                write!(f, "{name} failed ({status})")?;
+
+                if !stderr.is_empty() {
+                    write!(f, ": {stderr}")?;
+                }
+
+                Ok(())
+            }
+            Self::InvalidAction {
+                name,
+                line,
+                message,
+            } => write!(
+                f,
+                "{name} replied with an invalid action '{line}': {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
@@ -0,0 +1,178 @@
+mod action;
+mod error;
+
+use std::{
+    fs,
+    io::Write,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+pub use action::PluginAction;
+pub use error::Error;
+use serde_json::Value;
+
+use crate::data::{PLUGINS_DIRECTORY_NAME, package};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Environment variable holding the socket of the instance that started the plugin
+pub const SOCKET_ENV: &str = "ARFIMA_SOCKET";
+
+/// An executable in the plugins directory, run as a command named after its file
+#[derive(Clone, Debug)]
+pub struct Plugin {
+    name: String,
+    path: PathBuf,
+}
+
+fn plugins_directory() -> Option<PathBuf> {
+    package::project_dirs().map(|dirs| dirs.config_dir().join(PLUGINS_DIRECTORY_NAME))
+}
+
+fn is_executable(path: &Path) -> bool {
+    fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+impl Plugin {
+    /// The plugin `name` in `directory`, if there is an executable by that name
+    pub fn find_in(directory: &Path, name: &str) -> Option<Self> {
+        if name.is_empty() || name.contains('/') || name.starts_with('.') {
+            return None;
+        }
+
+        let path = directory.join(name);
+
+        is_executable(&path).then(|| Self {
+            name: name.into(),
+            path,
+        })
+    }
+
+    /// The plugin `name` in the plugins directory of the config
+    pub fn find(name: &str) -> Option<Self> {
+        Self::find_in(&plugins_directory()?, name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the plugin in `directory` with `context` on its stdin and reads the actions it prints
+    pub fn run(
+        &self,
+        args: &[&str],
+        directory: Option<&Path>,
+        context: &Value,
+        socket: Option<&Path>,
+    ) -> Result<Vec<PluginAction>> {
+        let mut command = Command::new(&self.path);
+        command
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(directory) = directory {
+            command.current_dir(directory);
+        }
+        if let Some(socket) = socket {
+            command.env(SOCKET_ENV, socket);
+        }
+
+        let mut child = command.spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // a plugin that does not read its context closes stdin early, which is fine
+            let _ = writeln!(stdin, "{context}");
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(Error::Failed {
+                name: self.name.clone(),
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr)
+                    .lines()
+                    .last()
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| Error::InvalidAction {
+                    name: self.name.clone(),
+                    line: line.into(),
+                    message: e.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn write_plugin(directory: &Path, name: &str, script: &str) {
+        let path = directory.join(name);
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_find_needs_an_executable() {
+        let directory = tempfile::tempdir().unwrap();
+        write_plugin(directory.path(), "tool", "#!/bin/sh\n");
+        fs::write(directory.path().join("notes"), "").unwrap();
+
+        assert!(Plugin::find_in(directory.path(), "tool").is_some());
+        assert!(Plugin::find_in(directory.path(), "notes").is_none());
+        assert!(Plugin::find_in(directory.path(), "../tool").is_none());
+    }
+
+    #[test]
+    fn test_run_reads_context_and_actions() {
+        let directory = tempfile::tempdir().unwrap();
+        write_plugin(
+            directory.path(),
+            "echo-dir",
+            "#!/bin/sh\nread context\nprintf '{\"action\":\"message\",\"text\":\"%s %s\"}\\n' \"$1\" \"$(pwd)\"\necho '{\"action\":\"refresh\"}'\n",
+        );
+        write_plugin(
+            directory.path(),
+            "fail",
+            "#!/bin/sh\necho nope >&2\nexit 3\n",
+        );
+
+        let plugin = Plugin::find_in(directory.path(), "echo-dir").unwrap();
+        let actions = plugin
+            .run(&["hi"], Some(Path::new("/")), &json!({}), None)
+            .unwrap();
+
+        assert_eq!(
+            actions,
+            vec![
+                PluginAction::Message {
+                    text: "hi /".into()
+                },
+                PluginAction::Refresh
+            ]
+        );
+
+        let error = Plugin::find_in(directory.path(), "fail")
+            .unwrap()
+            .run(&[], None, &json!({}), None)
+            .unwrap_err();
+        assert!(error.to_string().ends_with(": nope"));
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    os::unix::net::UnixStream,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use super::{Error, Result, check_socket_directory, socket_directory};
+
+/// The socket of the most recently started instance that still accepts connections
+pub fn find_socket() -> Result<PathBuf> {
+    let directory = socket_directory()?;
+    if !directory.exists() {
+        return Err(Error::NoInstance);
+    }
+    check_socket_directory(&directory)?;
+
+    let mut sockets = fs::read_dir(&directory)
+        .map_err(|_| Error::NoInstance)?
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|e| e == "sock"))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect::<Vec<_>>();
+
+    sockets.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+
+    sockets
+        .into_iter()
+        .map(|(_, path)| path)
+        .find(|path| UnixStream::connect(path).is_ok())
+        .ok_or(Error::NoInstance)
+}
+
+/// Sends every line to the instance listening on `socket`, returning the answers in order
+pub fn send(socket: &Path, lines: &[String]) -> Result<Vec<Value>> {
+    let stream = UnixStream::connect(socket).map_err(|_| Error::NoInstance)?;
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    let mut answers = Vec::with_capacity(lines.len());
+
+    for line in lines {
+        writeln!(writer, "{}", line.replace('\n', " "))?;
+
+        let mut answer = String::new();
+        if reader.read_line(&mut answer)? == 0 {
+            return Err(Error::NoInstance);
+        }
+
+        answers.push(serde_json::from_str(&answer)?);
+    }
+
+    Ok(answers)
+}
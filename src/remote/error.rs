@@ -0,0 +1,45 @@
+use std::{fmt::Display, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    NoRuntimeDirectory,
+    /// The socket directory is not private to the current user
+    UnsafeDirectory(PathBuf),
+    /// No running instance to send requests to
+    NoInstance,
+    /// The instance did not answer in time, e.g. while an editor it started is open
+    Timeout,
+    Json(serde_json::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO error: {e}"),
+            Self::NoRuntimeDirectory => write!(f, "Failed to find a directory for the socket"),
+            Self::UnsafeDirectory(path) => write!(
+                f,
+                "{} must be a directory owned by you with mode 700",
+                path.display()
+            ),
+            Self::NoInstance => write!(f, "No running arfima instance was found"),
+            Self::Timeout => write!(f, "The arfima instance did not answer"),
+            Self::Json(e) => write!(f, "JSON error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
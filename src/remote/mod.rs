@@ -0,0 +1,81 @@
+mod client;
+mod error;
+mod request;
+mod server;
+
+use std::{
+    env, fs,
+    os::unix::fs::{MetadataExt, PermissionsExt},
+    path::{Path, PathBuf},
+};
+
+pub use client::{find_socket, send};
+pub use error::Error;
+pub use request::{Query, Request};
+use serde_json::{Value, json};
+pub use server::Server;
+
+use crate::data::package;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Where instances put their sockets, the runtime directory or one named after the user in the
+/// temp directory
+fn socket_directory() -> Result<PathBuf> {
+    match package::project_dirs() {
+        Some(dirs) => Ok(dirs
+            .runtime_dir()
+            .map(|directory| directory.to_owned())
+            .unwrap_or_else(|| {
+                env::temp_dir().join(format!(
+                    "{}-{}",
+                    package::get().application(),
+                    rustix::process::getuid().as_raw()
+                ))
+            })),
+        None => Err(Error::NoRuntimeDirectory),
+    }
+}
+
+/// Fails unless `directory` is a real directory owned by the current user that only they can
+/// access, so nobody else can plant or listen on sockets in it
+fn check_socket_directory(directory: &Path) -> Result<()> {
+    let metadata = fs::symlink_metadata(directory)?;
+
+    if !metadata.is_dir()
+        || metadata.uid() != rustix::process::getuid().as_raw()
+        || metadata.permissions().mode() & 0o777 != 0o700
+    {
+        return Err(Error::UnsafeDirectory(directory.to_owned()));
+    }
+
+    Ok(())
+}
+
+/// `{"ok": false, "error": message}`
+pub fn error_response(message: &str) -> Value {
+    json!({ "ok": false, "error": message })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+
+    use super::*;
+
+    #[test]
+    fn test_check_socket_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let directory = root.path().join("sockets");
+        let link = root.path().join("link");
+        fs::create_dir(&directory).unwrap();
+        symlink(&directory, &link).unwrap();
+
+        fs::set_permissions(&directory, fs::Permissions::from_mode(0o700)).unwrap();
+        assert!(check_socket_directory(&directory).is_ok());
+        assert!(check_socket_directory(&link).is_err());
+
+        fs::set_permissions(&directory, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(check_socket_directory(&directory).is_err());
+    }
+}
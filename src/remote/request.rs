@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+/// What a query line asks for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Query {
+    Directory,
+    Selected,
+    Marked,
+    /// Everything above in one answer
+    Context,
+}
+
+impl FromStr for Query {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "directory" => Ok(Self::Directory),
+            "selected" => Ok(Self::Selected),
+            "marked" => Ok(Self::Marked),
+            "context" => Ok(Self::Context),
+            _ => Err(format!("Unknown query: {s}")),
+        }
+    }
+}
+
+/// One line sent to the socket
+#[derive(Debug, PartialEq)]
+pub enum Request {
+    /// A command line as typed after `:`, the `:` itself being optional
+    Command(String),
+    /// `?directory`, `?selected`, `?marked` or `?context`, answered with JSON
+    Query(Query),
+}
+
+impl FromStr for Request {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim();
+
+        if let Some(query) = line.strip_prefix('?') {
+            return Ok(Self::Query(query.trim().parse()?));
+        }
+
+        let command = line.strip_prefix(':').unwrap_or(line).trim_start();
+
+        if command.is_empty() {
+            return Err("Empty request".into());
+        }
+
+        Ok(Self::Command(command.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_requests() {
+        assert_eq!(
+            ":cd ~".parse::<Request>(),
+            Ok(Request::Command("cd ~".into()))
+        );
+        assert_eq!(
+            "set nu\n".parse::<Request>(),
+            Ok(Request::Command("set nu".into()))
+        );
+        assert_eq!(
+            "?marked".parse::<Request>(),
+            Ok(Request::Query(Query::Marked))
+        );
+        assert!("?size".parse::<Request>().is_err());
+        assert!(":".parse::<Request>().is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    fs::{self, DirBuilder},
+    io::{BufRead, BufReader, Write},
+    os::unix::{
+        fs::DirBuilderExt,
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use crossbeam::channel::{Sender, bounded};
+use serde_json::Value;
+
+use super::{Error, Request, Result, check_socket_directory, error_response, socket_directory};
+
+/// How long a connection waits for the app to answer a request
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+type OnRequest = dyn Fn(Request, Sender<Value>) -> bool + Send + Sync;
+
+/// Listens for requests while alive, removing the socket when dropped
+pub struct Server {
+    path: PathBuf,
+}
+
+impl Server {
+    /// Listens on a socket named after the process in the runtime directory, every request is
+    /// handed to `on_request` with a channel to answer on, returning false stops serving it
+    pub fn start(
+        on_request: impl Fn(Request, Sender<Value>) -> bool + Send + Sync + 'static,
+    ) -> Result<Self> {
+        let directory = socket_directory()?;
+        DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&directory)?;
+        check_socket_directory(&directory)?;
+
+        let path = directory.join(format!("{}.sock", process::id()));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+        let on_request: Arc<OnRequest> = Arc::new(on_request);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let on_request = on_request.clone();
+                thread::spawn(move || serve(stream, on_request.as_ref()));
+            }
+        });
+
+        Ok(Self { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Answers every line of the connection with one line of JSON
+fn serve(stream: UnixStream, on_request: &OnRequest) {
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            return;
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match line.parse::<Request>() {
+            Ok(request) => {
+                let (tx, rx) = bounded(1);
+
+                if !on_request(request, tx) {
+                    return;
+                }
+
+                rx.recv_timeout(REPLY_TIMEOUT)
+                    .unwrap_or_else(|_| error_response(&Error::Timeout.to_string()))
+            }
+            Err(e) => error_response(&e),
+        };
+
+        if writeln!(writer, "{response}").is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::{fmt::Display, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Serialization(toml::ser::Error),
+    NoDataDirectory,
+    InvalidSessionName(String),
+    SessionNotFound(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO error: {e}"),
+            Self::Parse(e) => write!(f, "Parse error: {e}"),
+            Self::Serialization(e) => write!(f, "Serialization error: {e}"),
+            Self::NoDataDirectory => write!(f, "Failed to find the data directory"),
+            Self::InvalidSessionName(name) => write!(f, "Invalid session name: {name}"),
+            Self::SessionNotFound(name) => write!(f, "No session named {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(value: toml::de::Error) -> Self {
+        Self::Parse(value)
+    }
+}
+
+impl From<toml::ser::Error> for Error {
+    fn from(value: toml::ser::Error) -> Self {
+        Self::Serialization(value)
+    }
+}
@@ -0,0 +1,255 @@
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Error, Result, data_file};
+
+const HISTORY_FILE_NAME: &str = "history.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HistoryKind {
+    Command,
+    /// Loaded, truncated and saved with the commands, nothing records searches yet
+    Search,
+}
+
+/// Entries of one history, oldest first
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct HistoryList {
+    entries: Vec<String>,
+}
+
+impl HistoryList {
+    /// Adds an entry unless it repeats the newest one, dropping the oldest above `size`
+    pub fn push(&mut self, entry: String, size: usize) {
+        if self.entries.last() != Some(&entry) {
+            self.entries.push(entry);
+        }
+
+        self.truncate(size);
+    }
+
+    /// Keeps the newest `size` entries
+    pub fn truncate(&mut self, size: usize) {
+        if self.entries.len() > size {
+            self.entries.drain(..self.entries.len() - size);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Index of the newest entry older than `from` starting with `prefix`, `from` being `None`
+    /// searches from the newest entry
+    pub fn previous(&self, prefix: &str, from: Option<usize>) -> Option<usize> {
+        let end = from.unwrap_or(self.entries.len()).min(self.entries.len());
+
+        self.entries[..end]
+            .iter()
+            .rposition(|entry| entry.starts_with(prefix))
+    }
+
+    /// Index of the oldest entry newer than `from` starting with `prefix`
+    pub fn next(&self, prefix: &str, from: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .skip(from + 1)
+            .find(|(_, entry)| entry.starts_with(prefix))
+            .map(|(index, _)| index)
+    }
+}
+
+/// The command line histories, kept in the data directory between sessions
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct History {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(skip)]
+    size: usize,
+    commands: HistoryList,
+    search: HistoryList,
+}
+
+impl History {
+    /// Loads the histories from the data directory, keeping the newest `size` entries of each
+    pub fn load(size: usize) -> Self {
+        let Some(path) = data_file(HISTORY_FILE_NAME) else {
+            return Self::with_size(size);
+        };
+
+        let mut history = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| toml::from_str::<Self>(&contents).ok())
+            .unwrap_or_default();
+
+        history.path = path;
+        history.set_size(size);
+
+        history
+    }
+
+    pub fn with_size(size: usize) -> Self {
+        Self {
+            size,
+            ..Self::default()
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if self.path.as_os_str().is_empty() {
+            return Err(Error::NoDataDirectory);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn list(&self, kind: HistoryKind) -> &HistoryList {
+        match kind {
+            HistoryKind::Command => &self.commands,
+            HistoryKind::Search => &self.search,
+        }
+    }
+
+    pub fn push(&mut self, kind: HistoryKind, entry: String) {
+        let size = self.size;
+
+        match kind {
+            HistoryKind::Command => self.commands.push(entry, size),
+            HistoryKind::Search => self.search.push(entry, size),
+        }
+    }
+
+    pub fn set_size(&mut self, size: usize) {
+        self.size = size;
+        self.commands.truncate(size);
+        self.search.truncate(size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(entries: &[&str]) -> HistoryList {
+        let mut list = HistoryList::default();
+        for entry in entries {
+            list.push(entry.to_string(), 10);
+        }
+        list
+    }
+
+    #[test]
+    fn test_push_skips_consecutive_duplicates_and_truncates() {
+        let mut list = list(&["set nu", "set nu", "w", "set nu"]);
+        assert_eq!(list.entries, vec!["set nu", "w", "set nu"]);
+
+        list.push("q".into(), 2);
+        assert_eq!(list.entries, vec!["set nu", "q"]);
+    }
+
+    #[test]
+    fn test_push_past_size_drops_oldest_in_order() {
+        let mut list = HistoryList::default();
+        for entry in ["1", "2", "3", "4", "5", "6", "7"] {
+            list.push(entry.into(), 3);
+        }
+
+        assert_eq!(list.entries, vec!["5", "6", "7"]);
+        assert_eq!(list.get(0), Some("5"));
+        assert_eq!(list.previous("", None), Some(2));
+
+        list.push("8".into(), 1);
+        assert_eq!(list.entries, vec!["8"]);
+
+        list.push("9".into(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_truncate_keeps_newest() {
+        let mut list = list(&["1", "2", "3", "4", "5"]);
+
+        list.truncate(10);
+        assert_eq!(list.len(), 5);
+
+        list.truncate(5);
+        assert_eq!(list.len(), 5);
+
+        list.truncate(2);
+        assert_eq!(list.entries, vec!["4", "5"]);
+
+        // growing again keeps what is left and takes new entries up to the new size
+        list.push("6".into(), 4);
+        list.push("7".into(), 4);
+        list.push("8".into(), 4);
+        assert_eq!(list.entries, vec!["5", "6", "7", "8"]);
+    }
+
+    #[test]
+    fn test_set_size_truncates_loaded_history() {
+        let mut history = History::with_size(5);
+        for entry in ["1", "2", "3", "4", "5"] {
+            history.push(HistoryKind::Command, entry.into());
+        }
+
+        history.set_size(2);
+        history.push(HistoryKind::Command, "6".into());
+
+        let commands = history.list(HistoryKind::Command);
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands.get(0), Some("5"));
+        assert_eq!(commands.get(1), Some("6"));
+    }
+
+    #[test]
+    fn test_prefix_navigation() {
+        let list = list(&["set nu", "cd ~", "set rnu", "w"]);
+
+        assert_eq!(list.previous("", None), Some(3));
+        assert_eq!(list.previous("set", None), Some(2));
+        assert_eq!(list.previous("set", Some(2)), Some(0));
+        assert_eq!(list.previous("set", Some(0)), None);
+        assert_eq!(list.next("set", 0), Some(2));
+        assert_eq!(list.next("set", 2), None);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("nested").join(HISTORY_FILE_NAME);
+
+        let mut history = History::with_size(2);
+        history.path = path.clone();
+        history.push(HistoryKind::Command, "set nu".into());
+        history.push(HistoryKind::Search, "foo".into());
+        history.push(HistoryKind::Search, "bar".into());
+        history.save().unwrap();
+
+        let mut loaded: History = toml::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(loaded.list(HistoryKind::Command).get(0), Some("set nu"));
+        assert_eq!(loaded.list(HistoryKind::Command).len(), 1);
+        assert_eq!(loaded.list(HistoryKind::Search).get(0), Some("foo"));
+
+        loaded.set_size(1);
+        assert_eq!(loaded.list(HistoryKind::Search).len(), 1);
+        assert_eq!(loaded.list(HistoryKind::Search).get(0), Some("bar"));
+    }
+}
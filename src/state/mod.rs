@@ -0,0 +1,20 @@
+mod error;
+mod history;
+mod opening_choices;
+mod session;
+
+use std::path::PathBuf;
+
+pub use error::Error;
+pub use history::{History, HistoryKind};
+pub use opening_choices::OpeningChoices;
+pub use session::{DEFAULT_SESSION, LAST_SESSION, Session};
+
+use crate::data::package;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Path of a file inside the data directory of the project
+fn data_file(name: &str) -> Option<PathBuf> {
+    package::project_dirs().map(|dirs| dirs.data_dir().join(name))
+}
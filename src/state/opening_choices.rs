@@ -0,0 +1,106 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Result, data_file};
+
+const OPENING_CHOICES_FILE_NAME: &str = "opening_choices.toml";
+
+/// The opening methods picked in the opening modal, keyed by file type
+#[derive(Default, Deserialize, Serialize)]
+pub struct OpeningChoices {
+    #[serde(default, skip_serializing)]
+    path: PathBuf,
+    /// The last method used for a file type, listed first in the opening modal
+    #[serde(default)]
+    last: HashMap<String, String>,
+    /// Methods picked with "always open with this", used when opening without the modal
+    #[serde(default)]
+    always: HashMap<String, String>,
+}
+
+impl OpeningChoices {
+    /// Loads the choices from the data directory, starting over when the file is missing or broken
+    pub fn load() -> Self {
+        let Some(path) = data_file(OPENING_CHOICES_FILE_NAME) else {
+            return Self::default();
+        };
+
+        let mut choices = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| toml::from_str::<Self>(&contents).ok())
+            .unwrap_or_default();
+
+        choices.path = path;
+
+        choices
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if self.path.as_os_str().is_empty() {
+            return Err(super::Error::NoDataDirectory);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn last(&self, file_type: &str) -> Option<&str> {
+        self.last.get(file_type).map(String::as_str)
+    }
+
+    pub fn always(&self, file_type: &str) -> Option<&str> {
+        self.always.get(file_type).map(String::as_str)
+    }
+
+    pub fn remember(&mut self, file_type: &str, method: &str, always: bool) {
+        self.last.insert(file_type.into(), method.into());
+
+        if always {
+            self.always.insert(file_type.into(), method.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remember() {
+        let mut choices = OpeningChoices::default();
+
+        choices.remember("image/png", "desktop:feh.desktop", false);
+        assert_eq!(choices.last("image/png"), Some("desktop:feh.desktop"));
+        assert_eq!(choices.always("image/png"), None);
+
+        choices.remember("image/png", "desktop:gimp.desktop", true);
+        choices.remember("image/png", "desktop:feh.desktop", false);
+        assert_eq!(choices.last("image/png"), Some("desktop:feh.desktop"));
+        assert_eq!(choices.always("image/png"), Some("desktop:gimp.desktop"));
+    }
+
+    #[test]
+    fn test_save_creates_the_data_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory
+            .path()
+            .join("nested")
+            .join(OPENING_CHOICES_FILE_NAME);
+        let mut choices = OpeningChoices {
+            path: path.clone(),
+            ..Default::default()
+        };
+
+        choices.remember(".log", "rule:less", true);
+        choices.save().unwrap();
+
+        let loaded: OpeningChoices = toml::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(loaded.always(".log"), Some("rule:less"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::WindowLayout;
+
+use super::{Error, Result, data_file};
+
+const SESSIONS_DIRECTORY_NAME: &str = "sessions";
+
+/// Saved by `:mksession` without a name
+pub const DEFAULT_SESSION: &str = "default";
+
+/// Saved when the app quits and restored on start when `session.restore` is on
+pub const LAST_SESSION: &str = "last";
+
+/// The window layout saved by `:mksession`
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Session {
+    layout: WindowLayout,
+}
+
+/// Session names are file names inside the sessions directory
+fn session_file(name: &str) -> Result<PathBuf> {
+    let mut components = Path::new(name).components();
+
+    if !matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    ) {
+        return Err(Error::InvalidSessionName(name.into()));
+    }
+
+    data_file(SESSIONS_DIRECTORY_NAME)
+        .map(|directory| directory.join(format!("{name}.toml")))
+        .ok_or(Error::NoDataDirectory)
+}
+
+impl Session {
+    pub fn new(layout: WindowLayout) -> Self {
+        Self { layout }
+    }
+
+    pub fn load(name: &str) -> Result<Self> {
+        let path = session_file(name)?;
+
+        if !path.is_file() {
+            return Err(Error::SessionNotFound(name.into()));
+        }
+
+        Ok(toml::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, name: &str) -> Result<()> {
+        let path = session_file(name)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn layout(self) -> WindowLayout {
+        self.layout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_names_are_file_names() {
+        assert!(!matches!(
+            session_file("work"),
+            Err(Error::InvalidSessionName(_))
+        ));
+        assert!(matches!(
+            session_file("../work"),
+            Err(Error::InvalidSessionName(_))
+        ));
+        assert!(matches!(
+            session_file("a/b"),
+            Err(Error::InvalidSessionName(_))
+        ));
+        assert!(matches!(
+            session_file(""),
+            Err(Error::InvalidSessionName(_))
+        ));
+    }
+}
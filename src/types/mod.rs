@@ -1,5 +1,3 @@
-mod circular_buffer;
 mod natural_string;
 
-pub use circular_buffer::CircularBuffer;
 pub use natural_string::NaturalString;
@@ -0,0 +1,231 @@
+use std::path::Path;
+
+/// Splits an `Exec=` value into arguments following the desktop entry specification, double
+/// quotes group an argument and inside them `\"`, `` \` ``, `\$` and `\\` are escapes.
+/// Returns `None` for an unterminated quote.
+pub fn split_exec(exec: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_argument = false;
+    let mut chars = exec.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_argument = true;
+
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            escaped @ ('"' | '`' | '$' | '\\') => current.push(escaped),
+                            other => {
+                                current.push('\\');
+                                current.push(other);
+                            }
+                        },
+                        other => current.push(other),
+                    }
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_argument {
+                    args.push(std::mem::take(&mut current));
+                    in_argument = false;
+                }
+            }
+            c => {
+                in_argument = true;
+                current.push(c);
+            }
+        }
+    }
+
+    if in_argument {
+        args.push(current);
+    }
+
+    Some(args)
+}
+
+/// What the field codes of an `Exec=` line expand to
+pub struct FieldCodes<'a> {
+    pub files: &'a [&'a Path],
+    pub icon: Option<&'a str>,
+    pub name: &'a str,
+    pub location: &'a Path,
+}
+
+/// Expands the field codes of already split arguments, codes for lists (`%F`, `%U`) and `%i` only
+/// expand when they are a whole argument. The files are appended when there is no file code.
+pub fn expand_field_codes(args: Vec<String>, codes: &FieldCodes) -> Vec<String> {
+    let mut expanded = Vec::with_capacity(args.len());
+    let mut files_used = false;
+    let first_file = || {
+        codes
+            .files
+            .first()
+            .map(|file| file.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+
+    for arg in args {
+        match arg.as_str() {
+            "%F" | "%U" => {
+                files_used = true;
+                expanded.extend(codes.files.iter().map(|f| f.to_string_lossy().to_string()));
+                continue;
+            }
+            "%i" => {
+                if let Some(icon) = codes.icon {
+                    expanded.push("--icon".into());
+                    expanded.push(icon.into());
+                }
+                continue;
+            }
+            "%f" | "%u" if codes.files.is_empty() => {
+                files_used = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut result = String::with_capacity(arg.len());
+        let mut chars = arg.chars();
+        // an argument made of removed codes only is dropped rather than passed empty
+        let mut only_removed = !arg.is_empty();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                only_removed = false;
+                continue;
+            }
+
+            match chars.next() {
+                Some('f' | 'u' | 'F' | 'U') => {
+                    files_used = true;
+                    result.push_str(&first_file());
+                }
+                Some('c') => result.push_str(codes.name),
+                Some('k') => result.push_str(codes.location.to_string_lossy().as_ref()),
+                Some('%') => result.push('%'),
+                // Deprecated and unknown codes are removed
+                _ => continue,
+            }
+            only_removed = false;
+        }
+
+        if !only_removed {
+            expanded.push(result);
+        }
+    }
+
+    if !files_used {
+        expanded.extend(codes.files.iter().map(|f| f.to_string_lossy().to_string()));
+    }
+
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_split_plain() {
+        assert_eq!(
+            split_exec("  vim   -p %F "),
+            Some(strings(&["vim", "-p", "%F"]))
+        );
+    }
+
+    #[test]
+    fn test_split_quoted() {
+        assert_eq!(
+            split_exec(r#""/opt/My App/bin" --title="a \"b\" \$c \\d" %u"#),
+            Some(strings(&[
+                "/opt/My App/bin",
+                "--title=a \"b\" $c \\d",
+                "%u"
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_split_empty_quotes_is_an_argument() {
+        assert_eq!(split_exec(r#"app "" x"#), Some(strings(&["app", "", "x"])));
+    }
+
+    #[test]
+    fn test_split_unterminated() {
+        assert_eq!(split_exec(r#"app "oops"#), None);
+    }
+
+    fn codes<'a>(files: &'a [&'a Path]) -> FieldCodes<'a> {
+        FieldCodes {
+            files,
+            icon: Some("viewer"),
+            name: "Viewer",
+            location: Path::new("/usr/share/applications/viewer.desktop"),
+        }
+    }
+
+    #[test]
+    fn test_expand_single_and_list() {
+        let files = [Path::new("/a b.png"), Path::new("/c.png")];
+
+        assert_eq!(
+            expand_field_codes(strings(&["viewer", "%f"]), &codes(&files)),
+            strings(&["viewer", "/a b.png"])
+        );
+        assert_eq!(
+            expand_field_codes(strings(&["viewer", "%U"]), &codes(&files)),
+            strings(&["viewer", "/a b.png", "/c.png"])
+        );
+    }
+
+    #[test]
+    fn test_expand_icon_name_location_and_percent() {
+        let files = [Path::new("/a.png")];
+
+        assert_eq!(
+            expand_field_codes(
+                strings(&[
+                    "viewer",
+                    "%i",
+                    "--class=%c",
+                    "%k",
+                    "100%%",
+                    "%d",
+                    "%D%n",
+                    "--file=%f"
+                ]),
+                &codes(&files)
+            ),
+            strings(&[
+                "viewer",
+                "--icon",
+                "viewer",
+                "--class=Viewer",
+                "/usr/share/applications/viewer.desktop",
+                "100%",
+                "--file=/a.png",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_expand_appends_files_without_codes() {
+        let files = [Path::new("/a.png")];
+
+        assert_eq!(
+            expand_field_codes(strings(&["viewer"]), &codes(&files)),
+            strings(&["viewer", "/a.png"])
+        );
+    }
+}
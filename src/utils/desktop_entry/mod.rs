@@ -0,0 +1,322 @@
+mod exec;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use exec::{FieldCodes, expand_field_codes, split_exec};
+
+use crate::utils::{
+    file::{FileError, FileResult},
+    ini::parse_ini,
+    shell::{find_executable, spawn_detached},
+    xdg_dirs::XdgDirs,
+};
+
+/// Decodes the escapes of desktop entry string values
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => result.push(' '),
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('\\') => result.push('\\'),
+            Some(other) => {
+                result.push('\\');
+                result.push(other);
+            }
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// `Name[..]` keys to look for, most specific first, based on `LC_ALL`, `LC_MESSAGES` and `LANG`
+fn locale_keys() -> Vec<String> {
+    let Some(locale) = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+    else {
+        return Vec::new();
+    };
+
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    let mut keys = vec![format!("Name[{locale}]")];
+
+    if let Some((language, _)) = locale.split_once('_') {
+        keys.push(format!("Name[{language}]"));
+    }
+
+    keys
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesktopEntry {
+    id: String,
+    location: PathBuf,
+    name: String,
+    exec: String,
+    try_exec: Option<String>,
+    working_directory: Option<PathBuf>,
+    icon: Option<String>,
+    terminal: bool,
+    hidden: bool,
+}
+
+impl DesktopEntry {
+    pub fn parse(id: &str, location: &Path, contents: &str) -> Option<Self> {
+        let locale_keys = locale_keys();
+        let mut name = None;
+        let mut localized_name: Option<(usize, String)> = None;
+        let mut exec = None;
+        let mut try_exec = None;
+        let mut working_directory = None;
+        let mut icon = None;
+        let mut terminal = false;
+        let mut hidden = false;
+        let mut is_application = true;
+
+        for entry in parse_ini(contents).filter(|entry| entry.section == "Desktop Entry") {
+            let value = unescape(entry.value);
+
+            match entry.key {
+                "Type" => is_application = value == "Application",
+                "Name" => name = Some(value),
+                "Exec" => exec = Some(value),
+                "TryExec" => try_exec = Some(value),
+                "Path" if !value.is_empty() => working_directory = Some(PathBuf::from(value)),
+                "Icon" if !value.is_empty() => icon = Some(value),
+                "Terminal" => terminal = value == "true",
+                "Hidden" => hidden = value == "true",
+                key => {
+                    if let Some(rank) = locale_keys.iter().position(|k| k == key)
+                        && localized_name.as_ref().is_none_or(|(r, _)| rank < *r)
+                    {
+                        localized_name = Some((rank, value));
+                    }
+                }
+            }
+        }
+
+        if !is_application {
+            return None;
+        }
+
+        let exec = exec?;
+
+        Some(Self {
+            id: id.into(),
+            location: location.to_owned(),
+            name: localized_name
+                .map(|(_, name)| name)
+                .or(name)
+                .unwrap_or_else(|| id.trim_end_matches(".desktop").into()),
+            exec,
+            try_exec,
+            working_directory,
+            icon,
+            terminal,
+            hidden,
+        })
+    }
+
+    /// Candidate paths for a desktop id relative to an applications directory, `a-b-c.desktop`
+    /// may also live at `a/b-c.desktop` or `a/b/c.desktop`
+    fn relative_paths(id: &str) -> Vec<PathBuf> {
+        let mut paths = vec![PathBuf::from(id)];
+        let mut prefix = PathBuf::new();
+        let mut rest = id;
+
+        while let Some((directory, remaining)) = rest.split_once('-') {
+            prefix.push(directory);
+            paths.push(prefix.join(remaining));
+            rest = remaining;
+        }
+
+        paths
+    }
+
+    /// Looks the desktop id up in the `applications` directory of every XDG data directory
+    pub fn find(id: &str, dirs: &XdgDirs) -> Option<Self> {
+        let id = if id.ends_with(".desktop") {
+            id.to_string()
+        } else {
+            format!("{id}.desktop")
+        };
+
+        for applications in dirs.data_paths().map(|path| path.join("applications")) {
+            for relative in Self::relative_paths(&id) {
+                let location = applications.join(relative);
+
+                if let Ok(contents) = fs::read_to_string(&location) {
+                    return Self::parse(&id, &location, &contents);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn terminal(&self) -> bool {
+        self.terminal
+    }
+
+    /// Hidden entries and entries whose `TryExec` is not installed should not be offered
+    pub fn is_available(&self) -> bool {
+        !self.hidden
+            && self
+                .try_exec
+                .as_deref()
+                .is_none_or(|program| find_executable(program).is_some())
+    }
+
+    /// The arguments to run for opening the files
+    pub fn command(&self, files: &[&Path]) -> FileResult<Vec<String>> {
+        let args = split_exec(&self.exec).ok_or(FileError::NoExecLine)?;
+
+        let args = expand_field_codes(
+            args,
+            &FieldCodes {
+                files,
+                icon: self.icon.as_deref(),
+                name: &self.name,
+                location: &self.location,
+            },
+        );
+
+        if args.is_empty() || args[0].is_empty() {
+            return Err(FileError::NoExecutableFound);
+        }
+
+        Ok(args)
+    }
+
+    /// Launches the application detached, or attached to the current terminal for `Terminal=true`
+    /// entries in which case the TUI needs to be suspended before calling this
+    pub fn launch(&self, files: &[&Path]) -> FileResult<()> {
+        let args = self.command(files)?;
+        let mut command = Command::new(&args[0]);
+        command.args(&args[1..]);
+
+        if let Some(directory) = &self.working_directory {
+            command.current_dir(directory);
+        }
+
+        if self.terminal {
+            if !command.status()?.success() {
+                return Err(FileError::FailedToOpenFile);
+            }
+        } else {
+            spawn_detached(&mut command)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let contents = "[Desktop Entry]\nType=Application\nName=Text Editor\nExec=\"/opt/text editor/bin\" --new-window %F\nTryExec=sh\nTerminal=false\nIcon=editor\n\n[Desktop Action new]\nName=New Window\nExec=other\n";
+        let entry = DesktopEntry::parse(
+            "editor.desktop",
+            Path::new("/usr/share/applications/editor.desktop"),
+            contents,
+        )
+        .unwrap();
+
+        assert_eq!(entry.name(), "Text Editor");
+        assert!(!entry.terminal());
+        assert!(entry.is_available());
+        assert_eq!(
+            entry.command(&[Path::new("/a.txt")]).unwrap(),
+            vec!["/opt/text editor/bin", "--new-window", "/a.txt"]
+        );
+    }
+
+    #[test]
+    fn test_parse_skips_non_applications_and_missing_exec() {
+        let location = Path::new("/x.desktop");
+
+        assert!(
+            DesktopEntry::parse(
+                "x.desktop",
+                location,
+                "[Desktop Entry]\nType=Link\nExec=x\n"
+            )
+            .is_none()
+        );
+        assert!(DesktopEntry::parse("x.desktop", location, "[Desktop Entry]\nName=X\n").is_none());
+    }
+
+    #[test]
+    fn test_unavailable() {
+        let location = Path::new("/x.desktop");
+        let hidden = DesktopEntry::parse(
+            "x.desktop",
+            location,
+            "[Desktop Entry]\nExec=x\nHidden=true\n",
+        )
+        .unwrap();
+        let missing = DesktopEntry::parse(
+            "x.desktop",
+            location,
+            "[Desktop Entry]\nExec=x\nTryExec=surely-not-an-installed-program\n",
+        )
+        .unwrap();
+
+        assert!(!hidden.is_available());
+        assert!(!missing.is_available());
+    }
+
+    #[test]
+    fn test_find_in_data_dirs() {
+        let data_home = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let applications = data_dir.path().join("applications/kde");
+
+        fs::create_dir_all(&applications).unwrap();
+        fs::write(
+            applications.join("viewer.desktop"),
+            "[Desktop Entry]\nName=Viewer\nExec=viewer %u\nTerminal=true\n",
+        )
+        .unwrap();
+
+        let dirs = XdgDirs::new(
+            "/nonexistent",
+            vec![],
+            data_home.path(),
+            vec![data_dir.path().to_path_buf()],
+        );
+        let entry = DesktopEntry::find("kde-viewer", &dirs).unwrap();
+
+        assert_eq!(entry.id(), "kde-viewer.desktop");
+        assert_eq!(entry.name(), "Viewer");
+        assert!(entry.terminal());
+        assert!(DesktopEntry::find("other", &dirs).is_none());
+    }
+}
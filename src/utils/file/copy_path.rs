@@ -0,0 +1,112 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{FileError, FileResult};
+
+/// Where `source` ends up inside `directory`, refusing to overwrite anything or to put a directory
+/// inside itself
+pub fn destination_in(source: &Path, directory: &Path) -> FileResult<PathBuf> {
+    let name = source.file_name().ok_or(FileError::FileDoesNotExists)?;
+    let destination = directory.join(name);
+
+    if destination.symlink_metadata().is_ok() {
+        return Err(FileError::AlreadyExists(destination));
+    }
+
+    if source.is_dir()
+        && let (Ok(source), Ok(directory)) = (source.canonicalize(), directory.canonicalize())
+        && directory.starts_with(&source)
+    {
+        return Err(FileError::IntoItself(source));
+    }
+
+    Ok(destination)
+}
+
+fn copy_recursively(source: &Path, destination: &Path) -> FileResult<()> {
+    let metadata = source.symlink_metadata()?;
+
+    if metadata.is_symlink() {
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(fs::read_link(source)?, destination)?;
+        #[cfg(not(unix))]
+        fs::copy(source, destination)?;
+    } else if metadata.is_dir() {
+        fs::create_dir(destination)?;
+
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursively(&entry.path(), &destination.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(source, destination)?;
+    }
+
+    Ok(())
+}
+
+/// Copies the file or directory at `source` into `directory`, returning the copy
+///
+/// Nothing is left behind when it fails, the destination did not exist before
+pub fn copy_path(source: &Path, directory: &Path) -> FileResult<PathBuf> {
+    let destination = destination_in(source, directory)?;
+
+    if let Err(e) = copy_recursively(source, &destination) {
+        let _ = match destination.symlink_metadata() {
+            Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(&destination),
+            _ => fs::remove_file(&destination),
+        };
+
+        return Err(e);
+    }
+
+    Ok(destination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_path_copies_directories() {
+        let root = tempfile::tempdir().unwrap();
+        let source = root.path().join("source");
+        let target = root.path().join("target");
+        fs::create_dir_all(source.join("nested")).unwrap();
+        fs::create_dir(&target).unwrap();
+        fs::write(source.join("nested/file"), "contents").unwrap();
+
+        let copy = copy_path(&source, &target).unwrap();
+
+        assert_eq!(copy, target.join("source"));
+        assert_eq!(
+            fs::read_to_string(copy.join("nested/file")).unwrap(),
+            "contents"
+        );
+        assert!(matches!(
+            copy_path(&source, &target),
+            Err(FileError::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            copy_path(&source, &source.join("nested")),
+            Err(FileError::IntoItself(_))
+        ));
+    }
+
+    #[test]
+    fn test_failed_copy_leaves_nothing() {
+        let root = tempfile::tempdir().unwrap();
+        let source = root.path().join("source");
+        let target = root.path().join("target");
+        fs::create_dir_all(source.join("a")).unwrap();
+        fs::create_dir(&target).unwrap();
+        fs::write(source.join("a/file"), "contents").unwrap();
+        // sockets can't be copied
+        let _listener = std::os::unix::net::UnixListener::bind(source.join("b.sock")).unwrap();
+
+        assert!(copy_path(&source, &target).is_err());
+        assert!(!target.join("source").exists());
+    }
+}
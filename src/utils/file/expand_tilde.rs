@@ -19,10 +19,16 @@ static HOME: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
 });
 
 pub fn expand_tilde(path: &str) -> PathBuf {
-    if let Some(stripped) = path.strip_prefix("~/") {
-        if let Some(home) = &*HOME {
-            return home.join(stripped);
-        }
+    if path == "~"
+        && let Some(home) = &*HOME
+    {
+        return home.clone();
+    }
+
+    if let Some(stripped) = path.strip_prefix("~/")
+        && let Some(home) = &*HOME
+    {
+        return home.join(stripped);
     }
 
     PathBuf::from(path)
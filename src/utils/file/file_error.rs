@@ -1,10 +1,8 @@
-use std::{env, fmt::Display, io};
+use std::{env, fmt::Display, io, path::PathBuf};
 
 #[derive(Debug)]
 pub enum FileError {
     FileDoesNotExists,
-    FailedToGetMimeType,
-    FailedToGetAppsWithMimeType,
     Io(io::Error),
     CouldNotDetermineUTI,
     NoFileExtension,
@@ -15,14 +13,14 @@ pub enum FileError {
     VarError(env::VarError),
     NoExecLine,
     NoExecutableFound,
+    AlreadyExists(PathBuf),
+    IntoItself(PathBuf),
 }
 
 impl Display for FileError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FileDoesNotExists => write!(f, "File does not exists"),
-            Self::FailedToGetMimeType => write!(f, "Failed to get mime type"),
-            Self::FailedToGetAppsWithMimeType => write!(f, "Failed to get apps with mime type"),
             Self::Io(e) => write!(f, "IO error: {e}"),
             Self::CouldNotDetermineUTI => write!(f, "Could not determine UTI"),
             Self::NoFileExtension => write!(f, "No file extension"),
@@ -33,6 +31,10 @@ impl Display for FileError {
             Self::VarError(e) => write!(f, "Var error: {e}"),
             Self::NoExecLine => write!(f, "No exec line"),
             Self::NoExecutableFound => write!(f, "No executable found"),
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::IntoItself(path) => {
+                write!(f, "Cannot put {} inside itself", path.display())
+            }
         }
     }
 }
@@ -0,0 +1,13 @@
+use std::path::Path;
+
+use crate::utils::mime::{detect_mime_type, mime_database};
+
+use super::{FileError, FileResult};
+
+pub fn get_mime_type(path: &Path) -> FileResult<String> {
+    if !path.exists() {
+        return Err(FileError::FileDoesNotExists);
+    }
+
+    Ok(detect_mime_type(path, mime_database())?)
+}
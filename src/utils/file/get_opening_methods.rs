@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+#[cfg(any(target_os = "windows", target_os = "macos"))]
 use std::process::Command;
 
 #[cfg(target_os = "macos")]
@@ -6,9 +7,18 @@ use std::collections::HashSet;
 #[cfg(any(target_os = "windows", target_os = "macos"))]
 use std::ffi::OsStr;
 
-use super::{FileError, FileResult};
+#[cfg(target_os = "linux")]
+use crate::utils::{
+    desktop_entry::DesktopEntry,
+    mime::{MimeAssociations, mime_database},
+    xdg_dirs::XdgDirs,
+};
+
+#[cfg(target_os = "linux")]
+use super::get_mime_type;
+use super::{FileError, FileResult, OpeningMethod};
 
-pub fn get_opening_methods(path: &PathBuf) -> FileResult<Vec<String>> {
+pub fn get_opening_methods(path: &PathBuf) -> FileResult<Vec<OpeningMethod>> {
     let path = Path::new(path);
     if !path.exists() {
         return Err(FileError::FileDoesNotExists);
@@ -16,37 +26,17 @@ pub fn get_opening_methods(path: &PathBuf) -> FileResult<Vec<String>> {
 
     #[cfg(target_os = "linux")]
     {
-        use std::collections::HashSet;
-
-        let mime_type = {
-            let output = Command::new("xdg-mime")
-                .arg("query")
-                .arg("filetype")
-                .arg(path)
-                .output()?;
-            if !output.status.success() {
-                return Err(FileError::FailedToGetMimeType);
-            }
-            String::from_utf8_lossy(&output.stdout).trim().to_string()
-        };
-
-        let output = Command::new("gio").arg("mime").arg(&mime_type).output()?;
-        if !output.status.success() {
-            return Err(FileError::FailedToGetAppsWithMimeType);
-        }
+        let mime_type = get_mime_type(path)?;
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let apps = stdout
-            .lines()
-            .filter(|line| line.contains(".desktop"))
-            .flat_map(|line| line.split_whitespace())
-            .filter(|word| word.ends_with(".desktop"))
-            .map(|s| s.to_string())
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .collect();
+        let dirs = XdgDirs::from_env();
 
-        return Ok(apps);
+        return Ok(MimeAssociations::load(&dirs)
+            .apps_for(&mime_type, mime_database())
+            .into_iter()
+            .filter_map(|id| DesktopEntry::find(&id, &dirs))
+            .filter(|entry| entry.is_available())
+            .map(OpeningMethod::Desktop)
+            .collect());
     }
 
     #[cfg(target_os = "macos")]
@@ -81,7 +71,7 @@ pub fn get_opening_methods(path: &PathBuf) -> FileResult<Vec<String>> {
             })
             .collect();
 
-        return Ok(apps.into_iter().collect());
+        return Ok(apps.into_iter().map(OpeningMethod::App).collect());
     }
 
     #[cfg(target_os = "windows")]
@@ -105,13 +95,13 @@ pub fn get_opening_methods(path: &PathBuf) -> FileResult<Vec<String>> {
             .output()?;
         let ftype_output = String::from_utf8_lossy(&ftype.stdout);
 
-        let apps: Vec<String> = ftype_output
+        let apps: Vec<OpeningMethod> = ftype_output
             .split('=')
             .nth(1)
             .unwrap_or("")
             .split_whitespace()
             .filter(|s| s.ends_with(".exe"))
-            .map(|s| s.trim_matches('"').to_string())
+            .map(|s| OpeningMethod::App(s.trim_matches('"').to_string()))
             .collect();
 
         return Ok(apps);
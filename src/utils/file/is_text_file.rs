@@ -0,0 +1,60 @@
+use std::{fs::File, io::Read, path::Path};
+
+const SNIFF_LENGTH: usize = 1024;
+
+/// Whether the bytes look like text, no NUL bytes and valid UTF-8
+pub fn is_text(bytes: &[u8]) -> bool {
+    if bytes.contains(&0) {
+        return false;
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(_) => true,
+        // The sniffed prefix may cut a multi-byte character in half
+        Err(e) => e.error_len().is_none(),
+    }
+}
+
+/// Guesses whether the file is text by looking at its first bytes
+pub fn is_text_file(path: &Path) -> bool {
+    let Ok(mut file) = File::open(path) else {
+        return false;
+    };
+
+    let mut buffer = [0; SNIFF_LENGTH];
+    match file.read(&mut buffer) {
+        Ok(read) => is_text(&buffer[..read]),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_is_text() {
+        assert!(is_text(b"fn main() {}\n"));
+    }
+
+    #[test]
+    fn test_empty_is_text() {
+        assert!(is_text(b""));
+    }
+
+    #[test]
+    fn test_nul_is_binary() {
+        assert!(!is_text(b"\x7fELF\x02\x01\x01\x00"));
+    }
+
+    #[test]
+    fn test_truncated_utf8_is_text() {
+        let bytes = "héllo".as_bytes();
+        assert!(is_text(&bytes[..2]));
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_binary() {
+        assert!(!is_text(b"\xff\xfe\xfd text"));
+    }
+}
@@ -1,17 +1,29 @@
 mod add_path;
+mod copy_path;
 mod delete_path;
 mod expand_tilde;
 mod file_error;
 mod file_result;
+mod get_mime_type;
 mod get_opening_methods;
+mod is_text_file;
+mod move_path;
 mod open_file;
+mod opening_method;
 mod rename_path;
+mod watch_file;
 
 pub use add_path::add_path;
+pub use copy_path::{copy_path, destination_in};
 pub use delete_path::delete_path;
 pub use expand_tilde::expand_tilde;
 pub use file_error::FileError;
 pub use file_result::FileResult;
+pub use get_mime_type::get_mime_type;
 pub use get_opening_methods::get_opening_methods;
-pub use open_file::{open_file, open_file_with_app};
+pub use is_text_file::{is_text, is_text_file};
+pub use move_path::move_path;
+pub use open_file::{open_file, open_file_in_terminal, open_file_with_app, open_file_with_command};
+pub use opening_method::OpeningMethod;
 pub use rename_path::rename_path;
+pub use watch_file::watch_file;
@@ -0,0 +1,28 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{FileResult, copy_path, destination_in};
+
+/// Moves the file or directory at `source` into `directory`, copying and removing it when the
+/// directory is on another file system
+pub fn move_path(source: &Path, directory: &Path) -> FileResult<PathBuf> {
+    let destination = destination_in(source, directory)?;
+
+    match fs::rename(source, &destination) {
+        Ok(_) => Ok(destination),
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let destination = copy_path(source, directory)?;
+
+            if source.is_dir() && !source.is_symlink() {
+                fs::remove_dir_all(source)?;
+            } else {
+                fs::remove_file(source)?;
+            }
+
+            Ok(destination)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
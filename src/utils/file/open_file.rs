@@ -1,9 +1,11 @@
-use std::{
-    path::Path,
-    process::{Command, Stdio},
-};
+#[cfg(target_os = "macos")]
+use std::process::Stdio;
+use std::{env, path::Path, process::Command};
 
-use crate::utils::file::FileError;
+use crate::utils::{
+    file::FileError,
+    shell::{run_in_shell, shell_quote, spawn_detached, spawn_in_shell},
+};
 
 use super::FileResult;
 
@@ -27,10 +29,62 @@ pub fn open_file(path: &Path) -> FileResult<()> {
         cmd.arg(path);
     }
 
-    cmd.stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .stdin(Stdio::null())
-        .spawn()?;
+    spawn_detached(&mut cmd)?;
+
+    Ok(())
+}
+
+/// The program used for opening files inside the terminal, `$VISUAL`, `$EDITOR` or `$PAGER` in
+/// that order falling back to `vi`
+fn terminal_program() -> String {
+    ["VISUAL", "EDITOR", "PAGER"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|program| !program.trim().is_empty())
+        .unwrap_or_else(|| "vi".into())
+}
+
+/// Runs `editor` or else the terminal program on the file in the foreground, the TUI needs to be
+/// suspended before calling this
+pub fn open_file_in_terminal(path: &Path, editor: Option<&str>) -> FileResult<()> {
+    if !path.exists() {
+        return Err(FileError::FileDoesNotExists);
+    }
+
+    let command = format!(
+        "{} {}",
+        editor.map_or_else(terminal_program, str::to_string),
+        shell_quote(path.to_string_lossy().as_ref())
+    );
+
+    if !run_in_shell(&command, path.parent(), false)?.success() {
+        return Err(FileError::FailedToOpenFile);
+    }
+
+    Ok(())
+}
+
+/// Runs a command with `{}` replaced by the quoted path, or the path appended when there is no
+/// `{}`. A foreground command needs the TUI to be suspended before calling this
+pub fn open_file_with_command(command: &str, path: &Path, foreground: bool) -> FileResult<()> {
+    if !path.exists() {
+        return Err(FileError::FileDoesNotExists);
+    }
+
+    let quoted = shell_quote(path.to_string_lossy().as_ref());
+    let command = if command.contains("{}") {
+        command.replace("{}", &quoted)
+    } else {
+        format!("{command} {quoted}")
+    };
+
+    if foreground {
+        if !run_in_shell(&command, path.parent(), false)?.success() {
+            return Err(FileError::FailedToOpenFile);
+        }
+    } else {
+        spawn_in_shell(&command, path.parent())?;
+    }
 
     Ok(())
 }
@@ -43,43 +97,11 @@ pub fn open_file_with_app(app: &str, file_path: &str) -> FileResult<()> {
 
     #[cfg(target_os = "linux")]
     {
-        use std::fs;
-
-        let desktop_file = if app.ends_with(".desktop") {
-            app.to_string()
-        } else {
-            format!("{app}.desktop")
-        };
-
-        let home = std::env::var("HOME")?;
-
-        let contents = fs::read_to_string(format!("/usr/share/applications/{desktop_file}"))
-            .or_else(|_| {
-                fs::read_to_string(format!("{home}/.local/share/applications/{desktop_file}",))
-            })?;
-
-        let exec_line = contents
-            .lines()
-            .find(|line| line.trim_start().starts_with("Exec="))
-            .ok_or(FileError::NoExecLine)?;
-
-        let exec_cmd = exec_line
-            .trim_start_matches("Exec=")
-            .split_whitespace()
-            .filter(|part| !part.starts_with('%'))
-            .collect::<Vec<_>>();
-
-        if exec_cmd.is_empty() {
-            return Err(FileError::NoExecutableFound);
-        }
+        use crate::utils::{desktop_entry::DesktopEntry, xdg_dirs::XdgDirs};
 
-        Command::new(exec_cmd[0])
-            .args(&exec_cmd[1..])
-            .arg(file_path)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .stdin(Stdio::null())
-            .spawn()?;
+        DesktopEntry::find(app, &XdgDirs::from_env())
+            .ok_or(FileError::NoExecLine)?
+            .launch(&[path])?;
     }
 
     #[cfg(target_os = "macos")]
@@ -100,12 +122,7 @@ pub fn open_file_with_app(app: &str, file_path: &str) -> FileResult<()> {
 
     #[cfg(target_os = "windows")]
     {
-        Command::new(app)
-            .arg(file_path)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .stdin(Stdio::null())
-            .spawn()?;
+        spawn_detached(Command::new(app).arg(file_path))?;
     }
 
     Ok(())
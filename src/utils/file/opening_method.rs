@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use crate::{config::OpenerRule, utils::desktop_entry::DesktopEntry};
+
+#[derive(Clone, Debug)]
+pub enum OpeningMethod {
+    /// A command from the `[[opener.rules]]` of the config
+    Rule(OpenerRule),
+    /// An application from a `.desktop` file
+    Desktop(DesktopEntry),
+    /// An application known to the platform opener
+    App(String),
+}
+
+impl OpeningMethod {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Rule(rule) => rule.name(),
+            Self::Desktop(entry) => entry.name(),
+            Self::App(app) => app,
+        }
+    }
+
+    /// Identifies the method across sessions
+    pub fn key(&self) -> String {
+        match self {
+            Self::Rule(rule) => format!("rule:{}", rule.name()),
+            Self::Desktop(entry) => format!("desktop:{}", entry.id()),
+            Self::App(app) => format!("app:{app}"),
+        }
+    }
+}
+
+impl Display for OpeningMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
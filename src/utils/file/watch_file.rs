@@ -0,0 +1,39 @@
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Polls the modification time of `path` on a background thread and calls `on_change` whenever it
+/// differs, including the file being created or removed. Polling instead of watching the
+/// directory keeps working when editors replace the file instead of writing to it. The thread stops
+/// once `on_change` returns false
+pub fn watch_file(
+    path: PathBuf,
+    interval: Duration,
+    mut on_change: impl FnMut() -> bool + Send + 'static,
+) {
+    thread::spawn(move || {
+        let mut last = modified(&path);
+
+        loop {
+            thread::sleep(interval);
+
+            let current = modified(&path);
+            if current != last {
+                last = current;
+
+                if !on_change() {
+                    break;
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// Appends log records to a file, the terminal being taken by the UI
+pub struct FileLogger {
+    file: Mutex<File>,
+    module: &'static str,
+    level: LevelFilter,
+}
+
+impl FileLogger {
+    /// Logs records of `module` up to `level` to the file at `path`
+    pub fn init(path: &Path, module: &'static str, level: LevelFilter) -> io::Result<()> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        let logger = Self {
+            file: Mutex::new(file),
+            module,
+            level,
+        };
+
+        log::set_boxed_logger(Box::new(logger))
+            .map(|_| log::set_max_level(level))
+            .map_err(|e: SetLoggerError| io::Error::other(e))
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level && metadata.target().starts_with(self.module)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(
+                file,
+                "{} {} {}: {}",
+                Local::now().format("%Y-%m-%d %H:%M:%S"),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
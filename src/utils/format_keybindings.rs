@@ -1,11 +1,6 @@
 use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::app::Precommand;
-
-pub fn format_keybindings(
-    bindings: &[(KeyModifiers, KeyCode)],
-    precommand: Option<&Precommand>,
-) -> String {
+pub fn format_keybindings(bindings: &[(KeyModifiers, KeyCode)]) -> String {
     bindings
         .iter()
         .map(|(modifiers, code)| {
@@ -43,18 +38,6 @@ pub fn format_keybindings(
                 _ => format!("{code:?}"),
             });
 
-            if let Some(precommand) = precommand {
-                match precommand {
-                    Precommand::Repeat(_) => {
-                        return format!("{{n}}{}", parts.join("+"));
-                    }
-                    Precommand::RepeatWindow(_) => {
-                        return format!("{{n}}Cnrtl+w {}", parts.join("+"));
-                    }
-                    Precommand::Leader => return format!("<leader> {}", parts.join("+")),
-                }
-            }
-
             parts.join("+")
         })
         .collect::<Vec<_>>()
@@ -0,0 +1,60 @@
+/// A `key=value` line of an ini-like file (desktop entries, `mimeapps.list`, ...)
+#[derive(Debug, PartialEq, Eq)]
+pub struct IniEntry<'a> {
+    pub section: &'a str,
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+/// Parses the `key=value` lines of an ini-like file keeping the section they belong to, comments
+/// and lines outside of a section are skipped
+pub fn parse_ini(contents: &str) -> impl Iterator<Item = IniEntry<'_>> {
+    let mut section = None;
+
+    contents.lines().filter_map(move |line| {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            return None;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = Some(name);
+            return None;
+        }
+
+        let (key, value) = line.split_once('=')?;
+
+        Some(IniEntry {
+            section: section?,
+            key: key.trim(),
+            value: value.trim(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ini() {
+        let contents = "ignored=1\n# comment\n[Default Applications]\ntext/plain=a.desktop;\n\n[Added Associations]\n image/png = b.desktop;c.desktop\nnot a pair\n";
+
+        assert_eq!(
+            parse_ini(contents).collect::<Vec<_>>(),
+            vec![
+                IniEntry {
+                    section: "Default Applications",
+                    key: "text/plain",
+                    value: "a.desktop;",
+                },
+                IniEntry {
+                    section: "Added Associations",
+                    key: "image/png",
+                    value: "b.desktop;c.desktop",
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::PathBuf,
+};
+
+use crate::utils::{ini::parse_ini, xdg_dirs::XdgDirs};
+
+use super::MimeDatabase;
+
+fn desktop_ids(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+}
+
+fn push_unique(list: &mut Vec<String>, ids: impl Iterator<Item = String>) {
+    for id in ids {
+        if !list.contains(&id) {
+            list.push(id);
+        }
+    }
+}
+
+/// Applications associated with MIME types by `mimeapps.list` files and the `mimeinfo.cache` of
+/// the applications directories
+#[derive(Default)]
+pub struct MimeAssociations {
+    defaults: HashMap<String, Vec<String>>,
+    added: HashMap<String, Vec<String>>,
+    removed: HashMap<String, HashSet<String>>,
+    cache: HashMap<String, Vec<String>>,
+}
+
+impl MimeAssociations {
+    /// `mimeapps.list` locations in order of preference
+    fn mimeapps_lists(dirs: &XdgDirs) -> Vec<PathBuf> {
+        dirs.config_paths()
+            .map(|path| path.join("mimeapps.list"))
+            .chain(
+                dirs.data_paths()
+                    .map(|path| path.join("applications").join("mimeapps.list")),
+            )
+            .collect()
+    }
+
+    pub fn load(dirs: &XdgDirs) -> Self {
+        let mut associations = Self::default();
+
+        for path in Self::mimeapps_lists(dirs) {
+            if let Ok(contents) = fs::read_to_string(path) {
+                associations.parse_mimeapps_list(&contents);
+            }
+        }
+
+        for path in dirs.data_paths() {
+            if let Ok(contents) =
+                fs::read_to_string(path.join("applications").join("mimeinfo.cache"))
+            {
+                associations.parse_mimeinfo_cache(&contents);
+            }
+        }
+
+        associations
+    }
+
+    fn parse_mimeapps_list(&mut self, contents: &str) {
+        for entry in parse_ini(contents) {
+            let key = entry.key.to_string();
+            let ids = desktop_ids(entry.value);
+
+            match entry.section {
+                "Default Applications" => push_unique(self.defaults.entry(key).or_default(), ids),
+                "Added Associations" => push_unique(self.added.entry(key).or_default(), ids),
+                "Removed Associations" => self.removed.entry(key).or_default().extend(ids),
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_mimeinfo_cache(&mut self, contents: &str) {
+        for entry in parse_ini(contents).filter(|entry| entry.section == "MIME Cache") {
+            push_unique(
+                self.cache.entry(entry.key.to_string()).or_default(),
+                desktop_ids(entry.value),
+            );
+        }
+    }
+
+    fn apps_for_exact(&self, mime_type: &str) -> Vec<String> {
+        let mut apps = Vec::new();
+
+        for map in [&self.defaults, &self.added, &self.cache] {
+            if let Some(ids) = map.get(mime_type) {
+                push_unique(&mut apps, ids.iter().cloned());
+            }
+        }
+
+        if let Some(removed) = self.removed.get(mime_type) {
+            apps.retain(|id| !removed.contains(id));
+        }
+
+        apps
+    }
+
+    /// Desktop ids for the type, defaults first, followed by the ones of the types it is a
+    /// subclass of
+    pub fn apps_for(&self, mime_type: &str, database: &MimeDatabase) -> Vec<String> {
+        let mime_type = database.canonical(mime_type);
+        let mut apps = self.apps_for_exact(mime_type);
+
+        for ancestor in database.ancestors(mime_type) {
+            push_unique(&mut apps, self.apps_for_exact(&ancestor).into_iter());
+        }
+
+        apps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_load_fixture() {
+        let config_home = tempfile::tempdir().unwrap();
+        let data_home = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+
+        fs::write(
+            config_home.path().join("mimeapps.list"),
+            "[Default Applications]\ntext/plain=nvim.desktop\n\n[Removed Associations]\ntext/plain=gedit.desktop;\n",
+        )
+        .unwrap();
+        fs::create_dir_all(data_dir.path().join("applications")).unwrap();
+        fs::write(
+            data_dir.path().join("applications/mimeapps.list"),
+            "[Default Applications]\ntext/plain=kate.desktop\n[Added Associations]\ntext/rust=code.desktop;\n",
+        )
+        .unwrap();
+        fs::write(
+            data_dir.path().join("applications/mimeinfo.cache"),
+            "[MIME Cache]\ntext/plain=gedit.desktop;kate.desktop;less.desktop;\n",
+        )
+        .unwrap();
+
+        let dirs = XdgDirs::new(
+            config_home.path(),
+            vec![],
+            data_home.path(),
+            vec![data_dir.path().to_path_buf()],
+        );
+        let associations = MimeAssociations::load(&dirs);
+        let database = MimeDatabase::default();
+
+        assert_eq!(
+            associations.apps_for("text/plain", &database),
+            vec!["nvim.desktop", "kate.desktop", "less.desktop"]
+        );
+        assert_eq!(
+            associations.apps_for("text/rust", &database),
+            vec![
+                "code.desktop",
+                "nvim.desktop",
+                "kate.desktop",
+                "less.desktop"
+            ]
+        );
+        assert!(associations.apps_for("image/png", &database).is_empty());
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, VecDeque},
+    fs,
+    path::Path,
+};
+
+use glob::Pattern;
+
+struct Glob {
+    weight: u32,
+    pattern: String,
+    mime_type: String,
+    case_sensitive: bool,
+}
+
+impl Glob {
+    fn matches(&self, name: &str, lowercase_name: &str) -> bool {
+        let (pattern, name) = if self.case_sensitive {
+            (self.pattern.clone(), name)
+        } else {
+            (self.pattern.to_lowercase(), lowercase_name)
+        };
+
+        if !pattern.contains(['*', '?', '[']) {
+            pattern == name
+        } else if let Some(suffix) = pattern.strip_prefix('*')
+            && !suffix.contains(['*', '?', '['])
+        {
+            name.ends_with(suffix)
+        } else {
+            Pattern::new(&pattern)
+                .map(|pattern| pattern.matches(name))
+                .unwrap_or(false)
+        }
+    }
+}
+
+/// The parts of the shared-mime-info database needed for detection, read from the `mime`
+/// directory of every XDG data directory
+#[derive(Default)]
+pub struct MimeDatabase {
+    globs: Vec<Glob>,
+    aliases: HashMap<String, String>,
+    parents: HashMap<String, Vec<String>>,
+}
+
+impl MimeDatabase {
+    /// `data_paths` are in order of preference, the first one wins on conflicts
+    pub fn load<'a>(data_paths: impl Iterator<Item = &'a Path>) -> Self {
+        let mut database = Self::default();
+
+        for mime_directory in data_paths.map(|path| path.join("mime")) {
+            if let Ok(contents) = fs::read_to_string(mime_directory.join("globs2")) {
+                database.parse_globs2(&contents);
+            } else if let Ok(contents) = fs::read_to_string(mime_directory.join("globs")) {
+                database.parse_globs(&contents);
+            }
+
+            if let Ok(contents) = fs::read_to_string(mime_directory.join("aliases")) {
+                database.parse_aliases(&contents);
+            }
+
+            if let Ok(contents) = fs::read_to_string(mime_directory.join("subclasses")) {
+                database.parse_subclasses(&contents);
+            }
+        }
+
+        database
+    }
+
+    fn lines(contents: &str) -> impl Iterator<Item = &str> {
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    }
+
+    fn parse_globs2(&mut self, contents: &str) {
+        for line in Self::lines(contents) {
+            let mut parts = line.split(':');
+
+            if let (Some(weight), Some(mime_type), Some(pattern)) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                self.globs.push(Glob {
+                    weight: weight.parse().unwrap_or(50),
+                    pattern: pattern.into(),
+                    mime_type: mime_type.into(),
+                    case_sensitive: parts.any(|flags| flags.split(',').any(|flag| flag == "cs")),
+                });
+            }
+        }
+    }
+
+    fn parse_globs(&mut self, contents: &str) {
+        for line in Self::lines(contents) {
+            if let Some((mime_type, pattern)) = line.split_once(':') {
+                self.globs.push(Glob {
+                    weight: 50,
+                    pattern: pattern.into(),
+                    mime_type: mime_type.into(),
+                    case_sensitive: false,
+                });
+            }
+        }
+    }
+
+    fn parse_aliases(&mut self, contents: &str) {
+        for line in Self::lines(contents) {
+            if let Some((alias, mime_type)) = line.split_once(' ') {
+                self.aliases
+                    .entry(alias.into())
+                    .or_insert_with(|| mime_type.trim().into());
+            }
+        }
+    }
+
+    fn parse_subclasses(&mut self, contents: &str) {
+        for line in Self::lines(contents) {
+            if let Some((mime_type, parent)) = line.split_once(' ') {
+                let parents = self.parents.entry(mime_type.into()).or_default();
+                let parent = parent.trim().to_string();
+
+                if !parents.contains(&parent) {
+                    parents.push(parent);
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.globs.is_empty()
+    }
+
+    /// Matches the file name against the globs, the highest weight wins and case-sensitive then
+    /// longer patterns then the earliest loaded break ties
+    pub fn mime_type_for_name(&self, name: &str) -> Option<&str> {
+        let lowercase_name = name.to_lowercase();
+
+        self.globs
+            .iter()
+            .enumerate()
+            .filter(|(_, glob)| glob.matches(name, &lowercase_name))
+            .max_by_key(|(index, glob)| {
+                (
+                    glob.weight,
+                    glob.case_sensitive,
+                    glob.pattern.len(),
+                    Reverse(*index),
+                )
+            })
+            .map(|(_, glob)| glob.mime_type.as_str())
+    }
+
+    pub fn canonical<'a>(&'a self, mime_type: &'a str) -> &'a str {
+        self.aliases
+            .get(mime_type)
+            .map(String::as_str)
+            .unwrap_or(mime_type)
+    }
+
+    /// All the types `mime_type` is a subclass of, closest first
+    pub fn ancestors(&self, mime_type: &str) -> Vec<String> {
+        let mut ancestors: Vec<String> = Vec::new();
+        let mut queue = VecDeque::from([self.canonical(mime_type).to_string()]);
+
+        while let Some(current) = queue.pop_front() {
+            let mut parents = self.parents.get(&current).cloned().unwrap_or_default();
+
+            if parents.is_empty() && current.starts_with("text/") && current != "text/plain" {
+                parents.push("text/plain".into());
+            }
+
+            for parent in parents {
+                if parent != mime_type && !ancestors.contains(&parent) {
+                    ancestors.push(parent.clone());
+                    queue.push_back(parent);
+                }
+            }
+        }
+
+        ancestors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn database() -> MimeDatabase {
+        let user = tempfile::tempdir().unwrap();
+        let system = tempfile::tempdir().unwrap();
+
+        fs::create_dir_all(user.path().join("mime")).unwrap();
+        fs::create_dir_all(system.path().join("mime")).unwrap();
+
+        fs::write(
+            user.path().join("mime/globs2"),
+            "# user overrides\n60:text/x-custom:*.rs\n50:text/x-user-markdown:*.md\n",
+        )
+        .unwrap();
+        fs::write(
+            system.path().join("mime/globs2"),
+            "50:text/rust:*.rs\n50:application/x-compressed-tar:*.tar.gz\n50:application/gzip:*.gz\n50:text/x-makefile:makefile\n50:text/x-c++src:*.C:cs\n50:text/x-csrc:*.c\n50:text/markdown:*.md\n",
+        )
+        .unwrap();
+        fs::write(
+            system.path().join("mime/aliases"),
+            "application/x-gzip application/gzip\n",
+        )
+        .unwrap();
+        fs::write(
+            system.path().join("mime/subclasses"),
+            "application/x-compressed-tar application/gzip\ntext/rust text/x-source\n",
+        )
+        .unwrap();
+
+        MimeDatabase::load([user.path(), system.path()].into_iter())
+    }
+
+    #[test]
+    fn test_weight_wins() {
+        assert_eq!(
+            database().mime_type_for_name("main.rs"),
+            Some("text/x-custom")
+        );
+    }
+
+    #[test]
+    fn test_first_directory_breaks_ties() {
+        assert_eq!(
+            database().mime_type_for_name("README.md"),
+            Some("text/x-user-markdown")
+        );
+    }
+
+    #[test]
+    fn test_longest_pattern_breaks_ties() {
+        assert_eq!(
+            database().mime_type_for_name("a.tar.gz"),
+            Some("application/x-compressed-tar")
+        );
+        assert_eq!(
+            database().mime_type_for_name("a.gz"),
+            Some("application/gzip")
+        );
+    }
+
+    #[test]
+    fn test_case_sensitivity() {
+        let database = database();
+
+        assert_eq!(
+            database.mime_type_for_name("Makefile"),
+            Some("text/x-makefile")
+        );
+        assert_eq!(database.mime_type_for_name("a.C"), Some("text/x-c++src"));
+        assert_eq!(database.mime_type_for_name("a.c"), Some("text/x-csrc"));
+        assert_eq!(database.mime_type_for_name("a.unknown"), None);
+    }
+
+    #[test]
+    fn test_aliases_and_ancestors() {
+        let database = database();
+
+        assert_eq!(database.canonical("application/x-gzip"), "application/gzip");
+        assert_eq!(
+            database.ancestors("application/x-compressed-tar"),
+            vec!["application/gzip".to_string()]
+        );
+        assert_eq!(
+            database.ancestors("text/rust"),
+            vec!["text/x-source".to_string(), "text/plain".to_string()]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+/// `(offset, signature, mime type)`, checked in order
+const SIGNATURES: &[(usize, &[u8], &str)] = &[
+    (0, b"\x89PNG\r\n\x1a\n", "image/png"),
+    (0, b"\xff\xd8\xff", "image/jpeg"),
+    (0, b"GIF87a", "image/gif"),
+    (0, b"GIF89a", "image/gif"),
+    (0, b"\x00\x00\x01\x00", "image/vnd.microsoft.icon"),
+    (0, b"II*\x00", "image/tiff"),
+    (0, b"MM\x00*", "image/tiff"),
+    (0, b"%PDF-", "application/pdf"),
+    (0, b"%!PS", "application/postscript"),
+    (0, b"{\\rtf", "application/rtf"),
+    (0, b"PK\x03\x04", "application/zip"),
+    (0, b"PK\x05\x06", "application/zip"),
+    (0, b"\x1f\x8b", "application/gzip"),
+    (0, b"BZh", "application/x-bzip2"),
+    (0, b"\xfd7zXZ\x00", "application/x-xz"),
+    (0, b"\x28\xb5\x2f\xfd", "application/zstd"),
+    (0, b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (0, b"Rar!\x1a\x07", "application/vnd.rar"),
+    (257, b"ustar", "application/x-tar"),
+    (0, b"\x7fELF", "application/x-executable"),
+    (0, b"\x00asm", "application/wasm"),
+    (0, b"\xca\xfe\xba\xbe", "application/x-java"),
+    (0, b"SQLite format 3\x00", "application/vnd.sqlite3"),
+    (0, b"ID3", "audio/mpeg"),
+    (0, b"fLaC", "audio/flac"),
+    (0, b"OggS", "audio/ogg"),
+    (0, b"\x1a\x45\xdf\xa3", "video/x-matroska"),
+    (0, b"wOFF", "font/woff"),
+    (0, b"wOF2", "font/woff2"),
+    (0, b"OTTO", "font/otf"),
+    (0, b"\x00\x01\x00\x00\x00", "font/ttf"),
+];
+
+fn read_u32_le(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// `BM` alone starts plenty of text files, the header sizes have to add up as well
+fn is_bmp(bytes: &[u8]) -> bool {
+    let (Some(file_size), Some(dib_size)) = (read_u32_le(bytes, 2), read_u32_le(bytes, 14)) else {
+        return false;
+    };
+
+    bytes.starts_with(b"BM")
+        && [12, 40, 56, 108, 124].contains(&dib_size)
+        && file_size >= 14 + dib_size
+}
+
+/// An MPEG-1 layer III frame header without ID3 tag, its bitrate and sample rate must be valid
+fn is_mp3_frame(bytes: &[u8]) -> bool {
+    match bytes {
+        [0xff, 0xfb, flags, ..] => {
+            let bitrate = flags >> 4;
+            let sample_rate = (flags >> 2) & 0b11;
+
+            bitrate != 0 && bitrate != 0b1111 && sample_rate != 0b11
+        }
+        _ => false,
+    }
+}
+
+/// Guesses the MIME type from the first bytes of a file
+pub fn sniff_mime_type(bytes: &[u8]) -> Option<&'static str> {
+    for (offset, signature, mime_type) in SIGNATURES {
+        if bytes.get(*offset..offset + signature.len()) == Some(signature) {
+            return Some(mime_type);
+        }
+    }
+
+    if is_bmp(bytes) {
+        return Some("image/bmp");
+    }
+    if is_mp3_frame(bytes) {
+        return Some("audio/mpeg");
+    }
+
+    // RIFF containers carry their format after the size
+    if bytes.starts_with(b"RIFF") {
+        match bytes.get(8..12) {
+            Some(b"WEBP") => return Some("image/webp"),
+            Some(b"WAVE") => return Some("audio/x-wav"),
+            Some(b"AVI ") => return Some("video/x-msvideo"),
+            _ => {}
+        }
+    }
+
+    // ISO base media files start with the size of the `ftyp` box
+    if bytes.get(4..8) == Some(b"ftyp") {
+        return match bytes.get(8..12) {
+            Some(b"avif") => Some("image/avif"),
+            Some(b"qt  ") => Some("video/quicktime"),
+            Some(b"M4A ") => Some("audio/mp4"),
+            _ => Some("video/mp4"),
+        };
+    }
+
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .map(|i| &bytes[i..])
+        .unwrap_or_default();
+    let starts_with = |prefix: &[u8]| {
+        start.len() >= prefix.len() && start[..prefix.len()].eq_ignore_ascii_case(prefix)
+    };
+
+    if starts_with(b"<!doctype html") || starts_with(b"<html") {
+        Some("text/html")
+    } else if starts_with(b"<svg") {
+        Some("image/svg+xml")
+    } else if starts_with(b"<?xml") {
+        Some("application/xml")
+    } else if starts_with(b"#!") {
+        Some("application/x-shellscript")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_png() {
+        assert_eq!(
+            sniff_mime_type(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"),
+            Some("image/png")
+        );
+    }
+
+    #[test]
+    fn test_tar_at_offset() {
+        let mut header = vec![0; 512];
+        header[257..262].copy_from_slice(b"ustar");
+
+        assert_eq!(sniff_mime_type(&header), Some("application/x-tar"));
+    }
+
+    #[test]
+    fn test_riff() {
+        assert_eq!(
+            sniff_mime_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "),
+            Some("image/webp")
+        );
+        assert_eq!(
+            sniff_mime_type(b"RIFF\x24\x00\x00\x00WAVEfmt "),
+            Some("audio/x-wav")
+        );
+    }
+
+    #[test]
+    fn test_weak_signatures_need_valid_headers() {
+        let mut bmp = b"BM\x46\x00\x00\x00\x00\x00\x00\x00\x36\x00\x00\x00".to_vec();
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+
+        assert_eq!(sniff_mime_type(&bmp), Some("image/bmp"));
+        assert_eq!(sniff_mime_type(b"BMI report for the last quarter"), None);
+        assert_eq!(sniff_mime_type(b"\xff\xfb\x90\x64\x00"), Some("audio/mpeg"));
+        assert_eq!(sniff_mime_type(b"\xff\xfb\xf0\x64\x00"), None);
+    }
+
+    #[test]
+    fn test_mp4() {
+        assert_eq!(
+            sniff_mime_type(b"\x00\x00\x00\x20ftypisom\x00\x00\x02\x00"),
+            Some("video/mp4")
+        );
+    }
+
+    #[test]
+    fn test_text_markers() {
+        assert_eq!(
+            sniff_mime_type(b"\n  <!DOCTYPE html><html>"),
+            Some("text/html")
+        );
+        assert_eq!(
+            sniff_mime_type(b"#!/bin/sh\necho hi"),
+            Some("application/x-shellscript")
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(sniff_mime_type(b"just some text"), None);
+        assert_eq!(sniff_mime_type(b""), None);
+    }
+}
@@ -0,0 +1,95 @@
+mod associations;
+mod database;
+mod magic;
+
+use std::{fs::File, io::Read, path::Path, sync::LazyLock};
+
+pub use associations::MimeAssociations;
+pub use database::MimeDatabase;
+pub use magic::sniff_mime_type;
+
+use crate::{data::mime_types::get_mime_types, utils::file::is_text};
+
+use super::xdg_dirs::XdgDirs;
+
+const SNIFF_LENGTH: usize = 1024;
+
+static DATABASE: LazyLock<MimeDatabase> =
+    LazyLock::new(|| MimeDatabase::load(XdgDirs::from_env().data_paths()));
+
+/// The shared-mime-info database of the system, loaded once
+pub fn mime_database() -> &'static MimeDatabase {
+    &DATABASE
+}
+
+/// Detects the MIME type of a path by name using the database (or the built-in extension table
+/// when it has no answer) and then by content
+pub fn detect_mime_type(path: &Path, database: &MimeDatabase) -> std::io::Result<String> {
+    if path.is_dir() {
+        return Ok("inode/directory".into());
+    }
+
+    if let Some(name) = path.file_name().map(|name| name.to_string_lossy())
+        && let Some(mime_type) = database.mime_type_for_name(&name)
+    {
+        return Ok(mime_type.into());
+    }
+
+    if let Some(mime_type) = path.extension().and_then(|extension| {
+        get_mime_types().get(extension.to_string_lossy().to_lowercase().as_str())
+    }) {
+        return Ok(mime_type.to_string());
+    }
+
+    let mut buffer = [0; SNIFF_LENGTH];
+    let read = File::open(path)?.read(&mut buffer)?;
+    let bytes = &buffer[..read];
+
+    Ok(if let Some(mime_type) = sniff_mime_type(bytes) {
+        mime_type.into()
+    } else if bytes.is_empty() {
+        "application/x-zerosize".into()
+    } else if is_text(bytes) {
+        "text/plain".into()
+    } else {
+        "application/octet-stream".into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_mime_type() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MimeDatabase::default();
+        let write = |name: &str, contents: &[u8]| {
+            let path = directory.path().join(name);
+            fs::write(&path, contents).unwrap();
+            path
+        };
+
+        let cases = [
+            (write("notes.MD", b"# notes"), "text/markdown"),
+            (write("picture", b"\x89PNG\r\n\x1a\n...."), "image/png"),
+            (write("README", b"plain words"), "text/plain"),
+            (
+                write("blob", b"\x00\x01\x02\xff"),
+                "application/octet-stream",
+            ),
+            (write("empty", b""), "application/x-zerosize"),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(detect_mime_type(&path, &database).unwrap(), expected);
+        }
+
+        assert_eq!(
+            detect_mime_type(directory.path(), &database).unwrap(),
+            "inode/directory"
+        );
+    }
+}
@@ -1,7 +1,14 @@
+pub mod desktop_entry;
 pub mod file;
+mod file_logger;
 mod format_keybindings;
 mod get_icon_and_color;
+pub mod ini;
+pub mod mime;
+pub mod shell;
 pub mod str;
+pub mod xdg_dirs;
 
+pub use file_logger::FileLogger;
 pub use format_keybindings::format_keybindings;
 pub use get_icon_and_color::get_icon_and_color;
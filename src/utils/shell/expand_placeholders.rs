@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use crate::app::WindowContext;
+
+use super::shell_quote;
+
+fn quote_path(path: Option<&PathBuf>) -> String {
+    shell_quote(
+        path.map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default()
+            .as_str(),
+    )
+}
+
+/// Expands the placeholders of a shell command using the focused window context
+///
+/// - `%f` the selected path
+/// - `%s` the marked paths, or the selected path when nothing is marked
+/// - `%d` the directory of the window
+/// - `%%` a literal `%`
+///
+/// Every expanded path is shell-quoted.
+pub fn expand_placeholders(command: &str, context: Option<&WindowContext>) -> String {
+    let mut expanded = String::with_capacity(command.len());
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            expanded.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('f') => {
+                expanded.push_str(&quote_path(context.and_then(|c| c.selected.as_ref())));
+            }
+            Some('s') => match context {
+                Some(context) if !context.marked.is_empty() => expanded.push_str(
+                    &context
+                        .marked
+                        .iter()
+                        .map(|path| quote_path(Some(path)))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+                _ => expanded.push_str(&quote_path(context.and_then(|c| c.selected.as_ref()))),
+            },
+            Some('d') => {
+                expanded.push_str(&quote_path(context.and_then(|c| c.directory.as_ref())));
+            }
+            Some('%') => expanded.push('%'),
+            _ => {
+                expanded.push('%');
+                continue;
+            }
+        }
+
+        chars.next();
+    }
+
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> WindowContext {
+        WindowContext {
+            id: 1,
+            directory: Some(PathBuf::from("/tmp/dir")),
+            selected: Some(PathBuf::from("/tmp/dir/a file")),
+            marked: vec![PathBuf::from("/tmp/dir/a"), PathBuf::from("/tmp/dir/b")],
+        }
+    }
+
+    #[test]
+    fn test_no_placeholders() {
+        assert_eq!(expand_placeholders("ls -la", Some(&context())), "ls -la");
+    }
+
+    #[test]
+    fn test_selected_is_quoted() {
+        assert_eq!(
+            expand_placeholders("cat %f", Some(&context())),
+            "cat '/tmp/dir/a file'"
+        );
+    }
+
+    #[test]
+    fn test_marked() {
+        assert_eq!(
+            expand_placeholders("rm %s", Some(&context())),
+            "rm /tmp/dir/a /tmp/dir/b"
+        );
+    }
+
+    #[test]
+    fn test_marked_falls_back_to_selected() {
+        let mut context = context();
+        context.marked.clear();
+
+        assert_eq!(
+            expand_placeholders("rm %s", Some(&context)),
+            "rm '/tmp/dir/a file'"
+        );
+    }
+
+    #[test]
+    fn test_directory_and_literal_percent() {
+        assert_eq!(
+            expand_placeholders("du %d | grep 100%% %x", Some(&context())),
+            "du /tmp/dir | grep 100% %x"
+        );
+    }
+
+    #[test]
+    fn test_without_context() {
+        assert_eq!(expand_placeholders("echo %f", None), "echo ''");
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    return path
+        .metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0);
+
+    #[cfg(not(unix))]
+    path.is_file()
+}
+
+/// Finds a program like the shell would, absolute paths are checked as they are
+pub fn find_executable(program: &str) -> Option<PathBuf> {
+    let path = Path::new(program);
+
+    if path.is_absolute() {
+        return is_executable(path).then(|| path.to_owned());
+    }
+
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|directory| directory.join(program))
+        .find(|candidate| is_executable(candidate))
+}
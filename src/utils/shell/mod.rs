@@ -0,0 +1,9 @@
+mod expand_placeholders;
+mod find_executable;
+mod run_in_shell;
+mod shell_quote;
+
+pub use expand_placeholders::expand_placeholders;
+pub use find_executable::find_executable;
+pub use run_in_shell::{run_in_shell, spawn_detached, spawn_in_shell};
+pub use shell_quote::shell_quote;
@@ -0,0 +1,80 @@
+use std::{
+    env, io,
+    path::Path,
+    process::{Command, ExitStatus, Stdio},
+    thread,
+};
+
+use crossterm::{
+    event::{self, Event, KeyEventKind},
+    terminal,
+};
+
+fn shell() -> String {
+    env::var("SHELL")
+        .ok()
+        .filter(|shell| !shell.is_empty())
+        .unwrap_or_else(|| "sh".into())
+}
+
+/// Runs `command` with `$SHELL -c` attached to the terminal and waits for it to finish
+///
+/// When `wait` is set the user is asked to press a key before returning so the output can be read.
+pub fn run_in_shell(command: &str, directory: Option<&Path>, wait: bool) -> io::Result<ExitStatus> {
+    let mut cmd = Command::new(shell());
+    cmd.arg("-c").arg(command);
+
+    if let Some(directory) = directory {
+        cmd.current_dir(directory);
+    }
+
+    let status = cmd.status()?;
+
+    if wait {
+        if !status.success() {
+            match status.code() {
+                Some(code) => println!("\nshell returned {code}"),
+                None => println!("\nshell was terminated by a signal"),
+            }
+        }
+
+        println!("\nPress any key to continue");
+
+        terminal::enable_raw_mode()?;
+        loop {
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                break;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Starts `command` without the terminal, waiting for it on another thread so it does not stay
+/// a zombie once it exits
+pub fn spawn_detached(command: &mut Command) -> io::Result<()> {
+    let mut child = command
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .stdin(Stdio::null())
+        .spawn()?;
+
+    thread::spawn(move || child.wait());
+
+    Ok(())
+}
+
+/// Runs `command` with `$SHELL -c` detached from the terminal
+pub fn spawn_in_shell(command: &str, directory: Option<&Path>) -> io::Result<()> {
+    let mut cmd = Command::new(shell());
+    cmd.arg("-c").arg(command);
+
+    if let Some(directory) = directory {
+        cmd.current_dir(directory);
+    }
+
+    spawn_detached(&mut cmd)
+}
@@ -0,0 +1,36 @@
+/// Quotes `s` so that a POSIX shell reads it back as a single word
+pub fn shell_quote(s: &str) -> String {
+    if !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/._-+=:,@%".contains(c))
+    {
+        return s.to_string();
+    }
+
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_word_is_untouched() {
+        assert_eq!(shell_quote("/home/user/file.txt"), "/home/user/file.txt");
+    }
+
+    #[test]
+    fn test_empty_is_quoted() {
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn test_spaces_are_quoted() {
+        assert_eq!(shell_quote("my file"), "'my file'");
+    }
+
+    #[test]
+    fn test_single_quote_is_escaped() {
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    }
+}
@@ -1,7 +1,9 @@
 mod all_but_first;
+mod closest_match;
 mod first_char_str;
 mod wrap_text;
 
 pub use all_but_first::all_but_first;
+pub use closest_match::closest_match;
 pub use first_char_str::first_char_str;
 pub use wrap_text::wrap_text;
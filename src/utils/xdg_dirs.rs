@@ -0,0 +1,87 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use directories::BaseDirs;
+
+fn home() -> PathBuf {
+    BaseDirs::new()
+        .map(|base_dirs| base_dirs.home_dir().to_path_buf())
+        .or_else(|| env::var_os("HOME").map(PathBuf::from))
+        .unwrap_or_default()
+}
+
+fn var_path(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+fn var_paths(name: &str, default: &[&str]) -> Vec<PathBuf> {
+    let paths = env::var(name)
+        .map(|value| {
+            value
+                .split(':')
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if paths.is_empty() {
+        default.iter().map(PathBuf::from).collect()
+    } else {
+        paths
+    }
+}
+
+/// The XDG base directories, in order of preference
+#[derive(Clone, Debug)]
+pub struct XdgDirs {
+    config_home: PathBuf,
+    config_dirs: Vec<PathBuf>,
+    data_home: PathBuf,
+    data_dirs: Vec<PathBuf>,
+}
+
+impl XdgDirs {
+    pub fn new(
+        config_home: impl Into<PathBuf>,
+        config_dirs: Vec<PathBuf>,
+        data_home: impl Into<PathBuf>,
+        data_dirs: Vec<PathBuf>,
+    ) -> Self {
+        Self {
+            config_home: config_home.into(),
+            config_dirs,
+            data_home: data_home.into(),
+            data_dirs,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self {
+            config_home: var_path("XDG_CONFIG_HOME").unwrap_or_else(|| home().join(".config")),
+            config_dirs: var_paths("XDG_CONFIG_DIRS", &["/etc/xdg"]),
+            data_home: var_path("XDG_DATA_HOME")
+                .unwrap_or_else(|| home().join(".local").join("share")),
+            data_dirs: var_paths("XDG_DATA_DIRS", &["/usr/local/share", "/usr/share"]),
+        }
+    }
+
+    pub fn config_home(&self) -> &Path {
+        &self.config_home
+    }
+
+    /// `$XDG_CONFIG_HOME` followed by `$XDG_CONFIG_DIRS`
+    pub fn config_paths(&self) -> impl Iterator<Item = &Path> {
+        std::iter::once(self.config_home.as_path())
+            .chain(self.config_dirs.iter().map(|p| p.as_path()))
+    }
+
+    /// `$XDG_DATA_HOME` followed by `$XDG_DATA_DIRS`
+    pub fn data_paths(&self) -> impl Iterator<Item = &Path> {
+        std::iter::once(self.data_home.as_path()).chain(self.data_dirs.iter().map(|p| p.as_path()))
+    }
+}